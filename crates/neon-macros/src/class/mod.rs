@@ -0,0 +1,196 @@
+use syn::spanned::Spanned;
+
+pub(crate) mod meta;
+
+// A method exposed on the class prototype
+struct Method {
+    ident: syn::Ident,
+    name: Option<syn::LitStr>,
+    mutable: bool,
+}
+
+pub(crate) fn class(meta: meta::Meta, mut item: syn::ItemImpl) -> proc_macro::TokenStream {
+    match expand(meta, &mut item) {
+        Ok(class_impl) => quote::quote!(
+            #item
+            #class_impl
+        )
+        .into(),
+        Err(err) => err.into_compile_error().into(),
+    }
+}
+
+fn expand(meta: meta::Meta, item: &mut syn::ItemImpl) -> syn::Result<proc_macro2::TokenStream> {
+    if let Some((_, path, _)) = &item.trait_ {
+        return Err(syn::Error::new(
+            path.span(),
+            "`neon::class` must be applied to an inherent `impl` block",
+        ));
+    }
+
+    if !item.generics.params.is_empty() {
+        return Err(syn::Error::new(
+            item.generics.span(),
+            "`neon::class` does not support generics",
+        ));
+    }
+
+    let ty = &item.self_ty;
+
+    // Default class name as the type name unless a name is provided
+    let class_name = match (meta.name, &**ty) {
+        (Some(name), _) => quote::quote!(#name),
+        (None, syn::Type::Path(path)) => {
+            let ident = &path.path.segments.last().unwrap().ident;
+
+            quote::quote!(stringify!(#ident))
+        }
+        (None, _) => {
+            return Err(syn::Error::new(
+                ty.span(),
+                "`neon::class` requires a `name` when the type is not a path",
+            ))
+        }
+    };
+
+    let mut constructor = None;
+    let mut methods = Vec::new();
+
+    for item in item.items.iter_mut() {
+        let syn::ImplItem::Fn(f) = item else {
+            continue;
+        };
+
+        // Remove the `constructor` and `method` attributes, since they are only markers
+        let mut attrs = Vec::new();
+
+        f.attrs.retain(|attr| {
+            let is_marker = attr.path().is_ident("constructor") || attr.path().is_ident("method");
+
+            if is_marker {
+                attrs.push(attr.clone());
+            }
+
+            !is_marker
+        });
+
+        for attr in attrs {
+            if attr.path().is_ident("constructor") {
+                attr.meta.require_path_only()?;
+
+                if f.sig.receiver().is_some() {
+                    return Err(syn::Error::new(
+                        f.sig.span(),
+                        "constructors must not take `self`",
+                    ));
+                }
+
+                if constructor.is_some() {
+                    return Err(syn::Error::new(
+                        attr.span(),
+                        "a class may only have one constructor",
+                    ));
+                }
+
+                constructor = Some(f.sig.ident.clone());
+
+                continue;
+            }
+
+            let mutable = match f.sig.receiver() {
+                Some(syn::Receiver {
+                    reference: Some(_),
+                    mutability,
+                    colon_token: None,
+                    ..
+                }) => mutability.is_some(),
+                _ => {
+                    return Err(syn::Error::new(
+                        f.sig.span(),
+                        "methods must take `&self` or `&mut self`",
+                    ))
+                }
+            };
+
+            let mut name = None;
+
+            if let syn::Meta::List(_) = attr.meta {
+                attr.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("name") {
+                        name = Some(meta.value()?.parse::<syn::LitStr>()?);
+
+                        return Ok(());
+                    }
+
+                    Err(meta.error("unsupported property"))
+                })?;
+            }
+
+            methods.push(Method {
+                ident: f.sig.ident.clone(),
+                name,
+                mutable,
+            });
+        }
+    }
+
+    let Some(constructor) = constructor else {
+        return Err(syn::Error::new(
+            item.self_ty.span(),
+            "`neon::class` requires a `#[constructor]` function",
+        ));
+    };
+
+    // Generate a wrapper function for each method that borrows the instance data
+    let wrappers = methods.iter().map(|method| {
+        let ident = &method.ident;
+        let call = if method.mutable {
+            quote::format_ident!("class_method_mut")
+        } else {
+            quote::format_ident!("class_method")
+        };
+
+        quote::quote!(
+            fn #ident<'cx>(
+                cx: neon::context::FunctionContext<'cx>,
+            ) -> neon::result::JsResult<'cx, neon::types::JsValue> {
+                neon::macro_internal::#call(cx, <#ty>::#ident)
+            }
+        )
+    });
+
+    // Default method name as identity unless a name is provided
+    let entries = methods.iter().map(|method| {
+        let ident = &method.ident;
+        let name = method
+            .name
+            .as_ref()
+            .map(|name| quote::quote!(#name))
+            .unwrap_or_else(|| quote::quote!(stringify!(#ident)));
+
+        quote::quote!(neon::macro_internal::ClassMethod {
+            name: #name,
+            method: #ident,
+        })
+    });
+
+    Ok(quote::quote!(
+        impl neon::class::Class for #ty {
+            const NAME: &'static str = #class_name;
+
+            fn __neon_construct(
+                cx: neon::context::FunctionContext,
+            ) -> neon::result::NeonResult<Self> {
+                <#ty>::#constructor(cx)
+            }
+
+            fn __neon_methods() -> &'static [neon::macro_internal::ClassMethod] {
+                #(#wrappers)*
+
+                const METHODS: &[neon::macro_internal::ClassMethod] = &[#(#entries),*];
+
+                METHODS
+            }
+        }
+    ))
+}
@@ -1,5 +1,6 @@
 //! Procedural macros supporting [Neon](https://docs.rs/neon/latest/neon/)
 
+mod class;
 mod export;
 
 #[proc_macro_attribute]
@@ -41,3 +42,14 @@ pub fn export(
 ) -> proc_macro::TokenStream {
     export::export(attr, item)
 }
+
+#[proc_macro_attribute]
+pub fn class(
+    attr: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let meta = syn::parse_macro_input!(attr with class::meta::Parser);
+    let item = syn::parse_macro_input!(item as syn::ItemImpl);
+
+    class::class(meta, item)
+}
@@ -0,0 +1,129 @@
+//! JavaScript classes backed by Rust data
+//!
+//! Classes are usually defined with the [`#[neon::class]`](macro@crate::class) attribute,
+//! which implements [`Class`] for a Rust type. Each JavaScript instance owns a value
+//! of that type, which is dropped when the instance is garbage collected.
+//!
+//! ```
+//! # use neon::prelude::*;
+//! struct Counter {
+//!     count: f64,
+//! }
+//!
+//! #[neon::class]
+//! impl Counter {
+//!     #[constructor]
+//!     fn new(mut cx: FunctionContext) -> NeonResult<Self> {
+//!         let count = cx.argument::<JsNumber>(0)?.value(&mut cx);
+//!
+//!         Ok(Self { count })
+//!     }
+//!
+//!     #[method]
+//!     fn increment<'cx>(&mut self, mut cx: FunctionContext<'cx>) -> JsResult<'cx, JsNumber> {
+//!         self.count += 1.0;
+//!
+//!         Ok(cx.number(self.count))
+//!     }
+//! }
+//!
+//! #[neon::main]
+//! fn main(mut cx: ModuleContext) -> NeonResult<()> {
+//!     cx.export_class::<Counter>()
+//! }
+//! ```
+//!
+//! ## Borrowing
+//!
+//! Methods borrow the instance for the duration of the call. If a method calls back into
+//! JavaScript and JavaScript re-enters a method on the same instance, the inner call
+//! throws an `Error` instead of aliasing `&mut self`.
+
+use std::{any::Any, cell::RefCell};
+
+use crate::{
+    context::{internal::ContextInternal, CallKind, Context, Cx, FunctionContext},
+    handle::Handle,
+    macro_internal::ClassMethod,
+    object::{Object, PropertyDescriptor},
+    result::{JsResult, NeonResult},
+    sys,
+    types::{private::ValueInternal, JsFunction, JsObject, JsValue},
+};
+
+pub(crate) type BoxAny = Box<dyn Any + 'static>;
+
+/// A Rust type exposed to JavaScript as a class.
+///
+/// This trait should be implemented with the [`#[neon::class]`](macro@crate::class) attribute.
+pub trait Class: Sized + 'static {
+    /// The name of the JavaScript class
+    const NAME: &'static str;
+
+    #[doc(hidden)]
+    fn __neon_construct(cx: FunctionContext) -> NeonResult<Self>;
+
+    #[doc(hidden)]
+    fn __neon_methods() -> &'static [ClassMethod];
+
+    /// Creates the JavaScript constructor for this class.
+    ///
+    /// Each call creates a distinct class, so this is typically called once during module
+    /// initialization and the result exported.
+    ///
+    /// **See also:** [`ModuleContext::export_class`](crate::context::ModuleContext::export_class)
+    fn constructor<'cx>(cx: &mut Cx<'cx>) -> JsResult<'cx, JsFunction> {
+        let constructor = JsFunction::with_name(cx, Self::NAME, construct::<Self>)?;
+        let prototype: Handle<JsObject> = constructor.prop(cx, "prototype").get()?;
+
+        // Methods are non-enumerable, matching the methods of a JavaScript `class`
+        for method in Self::__neon_methods() {
+            let f = JsFunction::with_name(cx, method.name, method.method)?;
+            let descriptor = PropertyDescriptor::new()
+                .value(f)
+                .writable(true)
+                .configurable(true);
+
+            prototype.define_property(cx, method.name, descriptor)?;
+        }
+
+        Ok(constructor)
+    }
+}
+
+fn construct<T: Class>(mut cx: FunctionContext) -> JsResult<JsValue> {
+    if let CallKind::Call = cx.kind() {
+        return cx.throw_type_error(format!(
+            "Class constructor {} cannot be invoked without 'new'",
+            T::NAME
+        ));
+    }
+
+    let env = cx.env().to_raw();
+    let this = cx.this_value();
+    let value = Box::new(RefCell::new(T::__neon_construct(cx)?)) as BoxAny;
+
+    // `this` is a newly created object and cannot already be wrapped
+    assert!(
+        unsafe { sys::wrap::wrap(env, this.to_local(), value) },
+        "Failed to wrap class instance"
+    );
+
+    Ok(this)
+}
+
+// Unwraps the Rust data of the `this` binding
+pub(crate) fn instance<'cx, T: Class>(
+    cx: &mut FunctionContext<'cx>,
+) -> NeonResult<&'cx RefCell<T>> {
+    let this = cx.this_value();
+
+    // Safety: The receiver is kept alive by the engine for the duration of the call
+    let data = unsafe { sys::wrap::unwrap::<BoxAny>(cx.env().to_raw(), this.to_local()) }
+        .and_then(|data| unsafe { &*data }.downcast_ref::<RefCell<T>>());
+
+    match data {
+        Some(data) => Ok(data),
+        None => cx.throw_type_error(format!("expected an instance of {}", T::NAME)),
+    }
+}
@@ -181,7 +181,8 @@ use crate::{
     },
     types::{
         boxed::{Finalize, JsBox},
-        error::JsError,
+        build,
+        error::{ErrnoInfo, JsError},
         extract::FromArgs,
         private::ValueInternal,
         Deferred, JsArray, JsArrayBuffer, JsBoolean, JsBuffer, JsFunction, JsNull, JsNumber,
@@ -200,6 +201,12 @@ use crate::types::date::{DateError, JsDate};
 #[cfg(feature = "napi-6")]
 use crate::lifecycle::InstanceData;
 
+#[cfg(feature = "napi-8")]
+use crate::{class::Class, types::JsExternal};
+
+#[cfg(all(feature = "napi-6", feature = "futures"))]
+use crate::types::extract::TryIntoJs;
+
 #[doc(hidden)]
 /// An execution context of a task completion callback.
 pub type TaskContext<'cx> = Cx<'cx>;
@@ -245,6 +252,109 @@ impl<'cx> Cx<'cx> {
         }
     }
 
+    /// Creates a JavaScript [async iterator](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Iteration_protocols#the_async_iterator_and_async_iterable_protocols)
+    /// that yields each value received from `rx`, so that it can be consumed with
+    /// `for await`.
+    ///
+    /// Each call to `next()` returns a promise that receives a single value on the
+    /// [global executor](crate::set_global_executor). The iterator completes with
+    /// `{ done: true }` once all senders have been dropped. Values are only received
+    /// when JavaScript requests them, so a bounded channel applies backpressure to the
+    /// producer. If JavaScript stops iterating and the iterator is garbage collected, the
+    /// receiver is dropped and sending fails.
+    ///
+    /// ```
+    /// # #[cfg(feature = "tokio-rt")]
+    /// # mod example {
+    /// # use neon::prelude::*;
+    /// use tokio::sync::mpsc;
+    ///
+    /// fn countdown(mut cx: FunctionContext) -> JsResult<JsObject> {
+    ///     let n = cx.argument::<JsNumber>(0)?.value(&mut cx);
+    ///     let (tx, rx) = mpsc::channel(1);
+    ///
+    ///     std::thread::spawn(move || {
+    ///         for i in (0..n as u32).rev() {
+    ///             if tx.blocking_send(i).is_err() {
+    ///                 break;
+    ///             }
+    ///         }
+    ///     });
+    ///
+    ///     cx.async_iterator(rx)
+    /// }
+    /// # }
+    /// ```
+    #[cfg(all(feature = "napi-6", feature = "futures"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "napi-6", feature = "futures"))))]
+    pub fn async_iterator<T>(
+        &mut self,
+        rx: tokio::sync::mpsc::Receiver<T>,
+    ) -> JsResult<'cx, JsObject>
+    where
+        T: for<'a> TryIntoJs<'a> + Send + 'static,
+    {
+        crate::executor::async_iterator(self, rx)
+    }
+
+    /// Schedules a closure to execute as a [microtask](https://developer.mozilla.org/docs/Web/API/HTML_DOM_API/Microtask_guide),
+    /// equivalent to calling [`queueMicrotask`](https://developer.mozilla.org/docs/Web/API/queueMicrotask)
+    /// in JavaScript.
+    ///
+    /// The closure executes on the current thread after the currently executing
+    /// JavaScript completes and before control returns to the event loop, in the same
+    /// queue as promise reactions. Use a [`Channel`] to schedule
+    /// work from other threads. If the closure throws, the exception is reported as
+    /// uncaught.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn defer(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    ///     let callback = cx.argument::<JsFunction>(0)?.root(&mut cx);
+    ///
+    ///     cx.queue_microtask(move |mut cx| callback.into_inner(&mut cx).bind(&mut cx).exec())?;
+    ///
+    ///     Ok(cx.undefined())
+    /// }
+    /// ```
+    #[cfg(feature = "napi-5")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-5")))]
+    pub fn queue_microtask<F>(&mut self, f: F) -> NeonResult<()>
+    where
+        F: FnOnce(Cx) -> NeonResult<()> + 'static,
+    {
+        // `JsFunction::new` requires `Fn`; the microtask is only called once
+        let f = std::cell::Cell::new(Some(f));
+        let task = JsFunction::new(self, move |mut cx| {
+            if let Some(f) = f.take() {
+                Cx::with_context(cx.env(), f)?;
+            }
+
+            Ok(cx.undefined())
+        })?;
+        let queue_microtask: Handle<JsFunction> = self.global("queueMicrotask")?;
+
+        queue_microtask.bind(self).arg(task)?.exec()
+    }
+
+    /// Forces a garbage collection with `global.gc`. Throws an `Error` if Node.js was
+    /// not started with the `--expose-gc` flag, e.g. `node --expose-gc test.js`.
+    ///
+    /// This is only available with the `expose-gc` feature. It is intended for testing
+    /// finalizers and [weak references](crate::handle::Weak) and should not be used in
+    /// production code. Values referenced by handles in the current scope, including
+    /// the arguments of the current function, are not collected.
+    #[cfg(feature = "expose-gc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "expose-gc")))]
+    pub fn request_gc(&mut self) -> NeonResult<()> {
+        let gc: Option<Handle<JsFunction>> = self.global_object().prop(self, "gc").get()?;
+
+        match gc {
+            Some(gc) => gc.bind(self).exec(),
+            None => self.throw_error("`global.gc` is not available; run node with `--expose-gc`"),
+        }
+    }
+
     fn new(env: Env) -> Self {
         Self {
             env,
@@ -354,6 +464,24 @@ impl CallbackInfo<'_> {
     }
 }
 
+#[cfg(feature = "napi-3")]
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-3")))]
+/// A closure registered with [`Context::add_cleanup_hook`]
+pub struct CleanupHook(sys::cleanup::CleanupHook);
+
+#[cfg(feature = "napi-3")]
+impl CleanupHook {
+    /// Removes the hook without running it. Returns `false` if the hook has already run.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cx` belongs to a different JavaScript environment than the one the
+    /// hook was registered with.
+    pub fn remove<'a, C: Context<'a>>(self, cx: &mut C) -> bool {
+        unsafe { sys::cleanup::remove(cx.env().to_raw(), self.0) }
+    }
+}
+
 /// Indicates whether a function was called with `new`.
 #[derive(Clone, Copy, Debug)]
 pub enum CallKind {
@@ -429,6 +557,91 @@ pub trait Context<'a>: ContextInternal<'a> {
         }
     }
 
+    /// Informs the garbage collector that `delta` bytes of memory outside the JavaScript
+    /// heap are being kept alive by JavaScript objects, e.g. a large buffer owned by a
+    /// [`JsBox`]. Returns the adjusted total of external memory.
+    ///
+    /// The JavaScript engine is not aware of memory allocated by Rust, so a small object
+    /// that owns a large allocation may not be collected promptly. Reporting the memory
+    /// allows the garbage collector to account for it. Memory should be reported with a
+    /// positive `delta` when it is allocated and with a negative `delta` when it is freed.
+    ///
+    /// Memory backing a [`JsArrayBuffer`] or [`JsBuffer`], including external buffers, is
+    /// already accounted for and should not be reported.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// struct Image(Vec<u8>);
+    ///
+    /// impl Finalize for Image {
+    ///     fn finalize<'a, C: Context<'a>>(self, cx: &mut C) {
+    ///         cx.adjust_external_memory(-(self.0.len() as i64));
+    ///     }
+    /// }
+    ///
+    /// fn load_image(mut cx: FunctionContext) -> JsResult<JsBox<Image>> {
+    ///     let data = vec![0; 500 * 1024 * 1024];
+    ///
+    ///     cx.adjust_external_memory(data.len() as i64);
+    ///
+    ///     Ok(cx.boxed(Image(data)))
+    /// }
+    /// ```
+    fn adjust_external_memory(&mut self, delta: i64) -> i64 {
+        unsafe { sys::mem::adjust_external_memory(self.env().to_raw(), delta) }
+    }
+
+    /// Returns the highest [Node-API version](https://nodejs.org/api/n-api.html#node-api-version-matrix)
+    /// supported by the running Node.js process.
+    ///
+    /// The value is read once when the addon is loaded, so this is cheap to call. If the
+    /// runtime is older than the `napi-*` feature selected at compile time, Neon prints
+    /// a warning when loading and any API that requires the newer version panics when
+    /// called. Checking the version allows an addon to fall back instead.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// use neon::types::JsBigInt;
+    ///
+    /// fn large_number(mut cx: FunctionContext) -> JsResult<JsValue> {
+    ///     let n = u64::MAX;
+    ///
+    ///     // `BigInt` requires Node-API 6
+    ///     if cx.napi_version() >= 6 {
+    ///         Ok(JsBigInt::from_u64(&mut cx, n).upcast())
+    ///     } else {
+    ///         Ok(cx.string(n.to_string()).upcast())
+    ///     }
+    /// }
+    /// ```
+    fn napi_version(&mut self) -> u32 {
+        sys::napi_version()
+    }
+
+    /// Checks if a JavaScript exception is pending, e.g. because a previous operation
+    /// threw and the resulting [`Throw`] has not been propagated.
+    ///
+    /// This is a low-level API for advanced use, such as sequences of calls that
+    /// intentionally ignore errors. Most code should propagate errors with `?` and never
+    /// need to inspect the exception state. While an exception is pending, most
+    /// operations fail.
+    fn is_exception_pending(&mut self) -> bool {
+        unsafe { sys::error::is_throwing(self.env().to_raw()) }
+    }
+
+    /// Returns an `Err` if a JavaScript exception is pending, so that it can be
+    /// propagated with `?`.
+    ///
+    /// Like [`Context::is_exception_pending`], this is a low-level API for advanced use.
+    fn throw_if_pending(&mut self) -> NeonResult<()> {
+        if self.is_exception_pending() {
+            // Safety: The VM is in a throwing state
+            return Err(unsafe { Throw::new() });
+        }
+
+        Ok(())
+    }
+
     /// Convenience method for creating a `JsBoolean` value.
     fn boolean(&mut self, b: bool) -> Handle<'a, JsBoolean> {
         JsBoolean::new(self, b)
@@ -518,6 +731,83 @@ pub trait Context<'a>: ContextInternal<'a> {
         })
     }
 
+    /// Parses a JSON string with the global `JSON.parse`.
+    ///
+    /// Throws a `SyntaxError` if `source` is not valid JSON.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// # fn parse_config(mut cx: FunctionContext) -> JsResult<JsValue> {
+    /// let config = cx.json_parse(r#"{ "name": "neon", "retries": 3 }"#)?;
+    /// # Ok(config)
+    /// # }
+    /// ```
+    fn json_parse<S: AsRef<str>>(&mut self, source: S) -> JsResult<'a, JsValue> {
+        let json: Handle<JsObject> = self.global("JSON")?;
+        let parse: Handle<JsFunction> = json.get(self, "parse")?;
+        let source = self.string(source).upcast();
+
+        parse.call(self, json, [source])
+    }
+
+    /// Serializes a value with the global `JSON.stringify`. When `indent` is provided,
+    /// the output is pretty printed with that many spaces per level.
+    ///
+    /// Throws a `TypeError` if the value has no JSON representation, e.g. `undefined`
+    /// or a function. Exceptions thrown while serializing, e.g. for circular
+    /// references, are propagated.
+    fn json_stringify<'b, V: Value>(
+        &mut self,
+        value: Handle<'b, V>,
+        indent: Option<u32>,
+    ) -> JsResult<'a, JsString> {
+        let json: Handle<JsObject> = self.global("JSON")?;
+        let stringify: Handle<JsFunction> = json.get(self, "stringify")?;
+        let mut args = vec![value.upcast::<JsValue>()];
+
+        if let Some(indent) = indent {
+            args.push(self.null().upcast());
+            args.push(self.number(indent).upcast());
+        }
+
+        let result = stringify.call(self, json, args)?;
+
+        match result.downcast::<JsString, _>(self) {
+            Ok(s) => Ok(s),
+            Err(_) => self.throw_type_error("value cannot be converted to JSON"),
+        }
+    }
+
+    /// Coerces a value to a number with the JavaScript rules of `Number(value)`.
+    ///
+    /// Exceptions thrown during coercion, e.g. when coercing a `Symbol`, are propagated.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn double(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    ///     let arg = cx.argument::<JsValue>(0)?;
+    ///     let n = cx.coerce_to_number(arg)?.value(&mut cx);
+    ///
+    ///     Ok(cx.number(n * 2.0))
+    /// }
+    /// ```
+    fn coerce_to_number<'b, V: Value>(&mut self, value: Handle<'b, V>) -> JsResult<'a, JsNumber> {
+        let env = self.env();
+
+        build(env, |out| unsafe {
+            sys::convert::to_number(out, env.to_raw(), value.to_local())
+        })
+    }
+
+    /// Coerces a value to a boolean with the JavaScript rules of `Boolean(value)`.
+    fn coerce_to_bool<'b, V: Value>(&mut self, value: Handle<'b, V>) -> JsResult<'a, JsBoolean> {
+        let env = self.env();
+
+        build(env, |out| unsafe {
+            sys::convert::to_bool(out, env.to_raw(), value.to_local())
+        })
+    }
+
     /// Throws a JS value.
     fn throw<T: Value, U>(&mut self, v: Handle<T>) -> NeonResult<U> {
         unsafe {
@@ -559,6 +849,127 @@ pub trait Context<'a>: ContextInternal<'a> {
         self.throw(err)
     }
 
+    /// Throws a direct instance of the [`Error`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/Error) class
+    /// with a `code` property.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// # fn example(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    /// cx.throw_error_with_code("ERR_INVALID_STATE", "the connection is closed")
+    /// # }
+    /// ```
+    fn throw_error_with_code<K: AsRef<str>, S: AsRef<str>, T>(
+        &mut self,
+        code: K,
+        msg: S,
+    ) -> NeonResult<T> {
+        let err = JsError::error_with_code(self, code, msg)?;
+        self.throw(err)
+    }
+
+    /// Throws an instance of the [`TypeError`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/TypeError) class
+    /// with a `code` property.
+    fn throw_type_error_with_code<K: AsRef<str>, S: AsRef<str>, T>(
+        &mut self,
+        code: K,
+        msg: S,
+    ) -> NeonResult<T> {
+        let err = JsError::type_error_with_code(self, code, msg)?;
+        self.throw(err)
+    }
+
+    /// Throws an instance of the [`RangeError`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/RangeError) class
+    /// with a `code` property.
+    fn throw_range_error_with_code<K: AsRef<str>, S: AsRef<str>, T>(
+        &mut self,
+        code: K,
+        msg: S,
+    ) -> NeonResult<T> {
+        let err = JsError::range_error_with_code(self, code, msg)?;
+        self.throw(err)
+    }
+
+    /// Creates an instance of the [`Error`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/Error) class
+    /// from a Rust error, chaining its [`source`](std::error::Error::source) as `cause`.
+    ///
+    /// **See also:** [`JsError::from_rust`]
+    fn error_from_rust(
+        &mut self,
+        err: &(dyn std::error::Error + 'static),
+    ) -> JsResult<'a, JsError> {
+        JsError::from_rust(self, err)
+    }
+
+    /// Throws an instance of the [`Error`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/Error) class
+    /// created from a Rust error.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn read_config(mut cx: FunctionContext) -> JsResult<JsString> {
+    ///     let path = cx.argument::<JsString>(0)?.value(&mut cx);
+    ///
+    ///     match std::fs::read_to_string(path) {
+    ///         Ok(contents) => Ok(cx.string(contents)),
+    ///         Err(err) => cx.throw_from_rust(&err),
+    ///     }
+    /// }
+    /// ```
+    fn throw_from_rust<T>(&mut self, err: &(dyn std::error::Error + 'static)) -> NeonResult<T> {
+        let err = JsError::from_rust(self, err)?;
+        self.throw(err)
+    }
+
+    /// Creates an instance of the [`Error`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/Error) class
+    /// resembling a Node.js [`SystemError`](https://nodejs.org/api/errors.html#class-systemerror).
+    ///
+    /// **See also:** [`JsError::system_error`]
+    fn system_error(&mut self, info: ErrnoInfo) -> JsResult<'a, JsError> {
+        JsError::system_error(self, info)
+    }
+
+    /// Creates an instance of the [`AggregateError`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/AggregateError)
+    /// class, reporting several `errors` at once.
+    ///
+    /// **See also:** [`JsError::aggregate_error`]
+    fn aggregate_error<V: Value, S: AsRef<str>>(
+        &mut self,
+        errors: &[Handle<'a, V>],
+        msg: S,
+    ) -> JsResult<'a, JsError> {
+        JsError::aggregate_error(self.cx_mut(), errors, msg)
+    }
+
+    /// Throws an instance of the [`AggregateError`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/AggregateError)
+    /// class, reporting several `errors` at once.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn validate(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    ///     let names = cx.argument::<JsArray>(0)?.to_vec(&mut cx)?;
+    ///     let mut errors = Vec::new();
+    ///
+    ///     for name in names {
+    ///         if !name.is_a::<JsString, _>(&mut cx) {
+    ///             errors.push(cx.type_error("expected a string")?);
+    ///         }
+    ///     }
+    ///
+    ///     if !errors.is_empty() {
+    ///         return cx.throw_aggregate_error(&errors, "invalid names");
+    ///     }
+    ///
+    ///     Ok(cx.undefined())
+    /// }
+    /// ```
+    fn throw_aggregate_error<V: Value, S: AsRef<str>, T>(
+        &mut self,
+        errors: &[Handle<'a, V>],
+        msg: S,
+    ) -> NeonResult<T> {
+        let err = JsError::aggregate_error(self.cx_mut(), errors, msg)?;
+        self.throw(err)
+    }
+
     /// Convenience method for wrapping a value in a `JsBox`.
     ///
     /// # Example:
@@ -579,6 +990,61 @@ pub trait Context<'a>: ContextInternal<'a> {
         JsBox::new(self, v)
     }
 
+    #[cfg(feature = "napi-8")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-8")))]
+    /// Convenience method for creating a [`JsExternal`] holding a raw pointer. The
+    /// `finalizer` is called with `ptr` when the value is garbage collected.
+    ///
+    /// **See also:** [`JsExternal::tagged`]
+    fn external<T>(&mut self, ptr: *mut T, finalizer: fn(*mut T)) -> Handle<'a, JsExternal> {
+        JsExternal::new(self, ptr, finalizer)
+    }
+
+    #[cfg(feature = "napi-3")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-3")))]
+    /// Registers a closure to run when the JavaScript environment is torn down, e.g.,
+    /// when the process exits or a worker thread stops.
+    ///
+    /// Cleanup hooks run on the JavaScript thread in reverse order of registration and
+    /// cannot call into JavaScript. They are the only reliable place to stop background
+    /// threads or release global resources owned by this instance of the module. A panic
+    /// in a hook is printed to stderr instead of aborting the process.
+    ///
+    /// The returned [`CleanupHook`] may be used to remove the hook if cleanup happens
+    /// earlier. Dropping it leaves the hook registered.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// # use std::sync::{atomic::{AtomicBool, Ordering}, Arc};
+    /// #[neon::main]
+    /// fn main(mut cx: ModuleContext) -> NeonResult<()> {
+    ///     let running = Arc::new(AtomicBool::new(true));
+    ///     let worker = {
+    ///         let running = running.clone();
+    ///
+    ///         std::thread::spawn(move || {
+    ///             while running.load(Ordering::Relaxed) {
+    ///                 std::thread::park();
+    ///             }
+    ///         })
+    ///     };
+    ///
+    ///     cx.add_cleanup_hook(move || {
+    ///         running.store(false, Ordering::Relaxed);
+    ///         worker.thread().unpark();
+    ///         let _ = worker.join();
+    ///     });
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    fn add_cleanup_hook<F>(&mut self, f: F) -> CleanupHook
+    where
+        F: FnOnce() + 'static,
+    {
+        CleanupHook(unsafe { sys::cleanup::add(self.env().to_raw(), f) })
+    }
+
     #[cfg(feature = "napi-4")]
     #[deprecated(since = "0.9.0", note = "Please use the channel() method instead")]
     #[doc(hidden)]
@@ -719,6 +1185,85 @@ impl<'cx> ModuleContext<'cx> {
         Ok(())
     }
 
+    #[cfg(feature = "napi-5")]
+    /// Exports a value from a Neon module that is computed on first access.
+    ///
+    /// The export is defined as an accessor property. The first time it is read, `f` is
+    /// called and the property is replaced with a data property holding the result, so
+    /// `f` runs at most once. If `f` throws, the exception is propagated and every later
+    /// access throws an `Error`.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// #[neon::main]
+    /// fn main(mut cx: ModuleContext) -> NeonResult<()> {
+    ///     cx.export_lazy("table", |cx| {
+    ///         let table = cx.empty_array();
+    ///
+    ///         for i in 0..256 {
+    ///             table.prop(cx, i).set(i * i)?;
+    ///         }
+    ///
+    ///         Ok(table)
+    ///     })
+    /// }
+    /// ```
+    pub fn export_lazy<F, V>(&mut self, key: &str, f: F) -> NeonResult<()>
+    where
+        F: for<'a> FnOnce(&mut Cx<'a>) -> JsResult<'a, V> + 'static,
+        V: Value,
+    {
+        use crate::object::PropertyDescriptor;
+
+        let exports = self.exports;
+        let name = key.to_owned();
+        let state = std::cell::Cell::new(Some(f));
+
+        // `this` is bound to `exports` below, so the cached value is always defined on
+        // `exports` and the closure does not need to hold a reference to it
+        let getter = JsFunction::new(self, move |mut cx| {
+            let exports = cx.this::<JsObject>()?;
+
+            // `None` if the value is being initialized or initialization failed
+            let Some(f) = state.take() else {
+                return cx.throw_error(format!("lazy export `{name}` failed to initialize"));
+            };
+
+            let value = f(&mut cx)?.upcast::<JsValue>();
+            let descriptor = PropertyDescriptor::new()
+                .value(value)
+                .writable(true)
+                .enumerable(true)
+                .configurable(true);
+
+            exports.define_property(&mut cx, name.as_str(), descriptor)?;
+
+            Ok(value)
+        })?;
+
+        // A bound `this` is an ordinary JavaScript reference that the garbage collector
+        // can trace, unlike a `Root` held by the closure
+        let getter = getter.bind_args(&mut self.cx, exports, &[])?;
+
+        // The accessor must be configurable to be replaced by the value
+        let descriptor = PropertyDescriptor::new()
+            .getter(getter)
+            .enumerable(true)
+            .configurable(true);
+
+        exports.define_property(self, key, descriptor)
+    }
+
+    /// Exports a class defined with [`#[neon::class]`](macro@crate::class) from a Neon module,
+    /// using the class name as the key.
+    #[cfg(feature = "napi-8")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-8")))]
+    pub fn export_class<T: Class>(&mut self) -> NeonResult<()> {
+        let constructor = T::constructor(&mut self.cx)?;
+
+        self.export_value(T::NAME, constructor)
+    }
+
     /// Produces a handle to a module's exports object.
     pub fn exports_object(&mut self) -> JsResult<'cx, JsObject> {
         Ok(self.exports)
@@ -791,6 +1336,46 @@ impl<'cx> FunctionContext<'cx> {
         self.len() == 0
     }
 
+    /// Throws a `TypeError` if fewer than `min` arguments were passed to the function,
+    /// e.g., `"expected at least 2 arguments, got 1"`.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn add(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    ///     cx.require_args(2)?;
+    ///
+    ///     let a = cx.argument::<JsNumber>(0)?.value(&mut cx);
+    ///     let b = cx.argument::<JsNumber>(1)?.value(&mut cx);
+    ///
+    ///     Ok(cx.number(a + b))
+    /// }
+    /// ```
+    pub fn require_args(&mut self, min: usize) -> NeonResult<()> {
+        let len = self.len();
+
+        if len < min {
+            return self
+                .throw_type_error(format!("expected at least {}, got {len}", plural_args(min)));
+        }
+
+        Ok(())
+    }
+
+    /// Throws a `TypeError` if fewer than `min` or more than `max` arguments were passed
+    /// to the function. This is useful for functions with optional trailing arguments.
+    pub fn require_args_range(&mut self, min: usize, max: usize) -> NeonResult<()> {
+        self.require_args(min)?;
+
+        let len = self.len();
+
+        if len > max {
+            return self
+                .throw_type_error(format!("expected at most {}, got {len}", plural_args(max)));
+        }
+
+        Ok(())
+    }
+
     /// Produces the `i`th argument, or `None` if `i` is greater than or equal to `self.len()`.
     pub fn argument_opt(&mut self, i: usize) -> Option<Handle<'cx, JsValue>> {
         let argv = if let Some(argv) = self.arguments.as_ref() {
@@ -812,6 +1397,67 @@ impl<'cx> FunctionContext<'cx> {
         }
     }
 
+    /// Produces the `i`th argument and casts it to the type `V`, or `default` if the
+    /// argument is missing or `undefined`. Throws an exception if the argument cannot be
+    /// cast to `V`.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn repeat(mut cx: FunctionContext) -> JsResult<JsString> {
+    ///     let s = cx.argument::<JsString>(0)?.value(&mut cx);
+    ///     let default = cx.number(2);
+    ///     let n = cx.argument_or(1, default)?.value(&mut cx);
+    ///
+    ///     Ok(cx.string(s.repeat(n as usize)))
+    /// }
+    /// ```
+    ///
+    /// **See also:** [`FunctionContext::argument_or_else`]
+    pub fn argument_or<V: Value>(&mut self, i: usize, default: Handle<'cx, V>) -> JsResult<'cx, V> {
+        self.argument_or_else(i, |_| Ok(default))
+    }
+
+    /// Produces the `i`th argument and casts it to the type `V`, or calls `f` to produce
+    /// a default if the argument is missing or `undefined`. Throws an exception if the
+    /// argument cannot be cast to `V`.
+    ///
+    /// Unlike [`FunctionContext::argument_or`], the default is only created when it is
+    /// needed. When extracting Rust values with [`FunctionContext::args`], use
+    /// `Option<T>` with [`Option::unwrap_or`] instead.
+    pub fn argument_or_else<V, F>(&mut self, i: usize, f: F) -> JsResult<'cx, V>
+    where
+        V: Value,
+        F: FnOnce(&mut Self) -> JsResult<'cx, V>,
+    {
+        match self.argument_opt(i) {
+            Some(v) if !v.is_a::<JsUndefined, _>(self) => v.downcast_or_throw(self),
+            _ => f(self),
+        }
+    }
+
+    /// Produces an iterator over all of the arguments passed to the function. Useful
+    /// for variadic functions.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn sum(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    ///     let mut total = 0.0;
+    ///
+    ///     for arg in cx.arguments() {
+    ///         total += arg.downcast_or_throw::<JsNumber, _>(&mut cx)?.value(&mut cx);
+    ///     }
+    ///
+    ///     Ok(cx.number(total))
+    /// }
+    /// ```
+    pub fn arguments(&mut self) -> impl Iterator<Item = Handle<'cx, JsValue>> {
+        let args = (0..self.len())
+            .filter_map(|i| self.argument_opt(i))
+            .collect::<Vec<_>>();
+
+        args.into_iter()
+    }
+
     /// Produces a handle to the `this`-binding and attempts to downcast as a specific type.
     /// Equivalent to calling `cx.this_value().downcast_or_throw(&mut cx)`.
     ///
@@ -875,6 +1521,14 @@ impl<'cx> FunctionContext<'cx> {
     }
 }
 
+fn plural_args(n: usize) -> String {
+    if n == 1 {
+        "1 argument".to_string()
+    } else {
+        format!("{n} arguments")
+    }
+}
+
 impl<'cx> ContextInternal<'cx> for FunctionContext<'cx> {
     fn cx(&self) -> &Cx<'cx> {
         &self.cx
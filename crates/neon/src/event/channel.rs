@@ -1,9 +1,11 @@
 use std::{
+    collections::VecDeque,
     error, fmt,
     sync::{
-        atomic::{AtomicUsize, Ordering},
-        Arc,
+        atomic::{AtomicU64, AtomicUsize, Ordering},
+        Arc, Condvar, Mutex,
     },
+    thread::{self, ThreadId},
 };
 
 use crate::{
@@ -51,6 +53,10 @@ type Callback = Box<dyn FnOnce(sys::Env) + Send + 'static>;
 /// Cloning a `Channel` will create a new channel that shares a backing queue for
 /// events.
 ///
+/// A `Channel` is unbounded by default. [`Channel::with_capacity`] creates a channel
+/// that limits the number of closures waiting to execute; [`Channel::try_send`] can
+/// then be used by producers to detect backpressure instead of growing the queue.
+///
 /// # Example
 ///
 /// The following example spawns a standard Rust thread to complete a computation
@@ -105,13 +111,61 @@ impl Channel {
     /// main thread
     pub fn new<'a, C: Context<'a>>(cx: &mut C) -> Self {
         Self {
-            state: Arc::new(ChannelState::new(cx)),
+            state: Arc::new(ChannelState::new(cx, None, None)),
+            has_ref: true,
+        }
+    }
+
+    /// Creates a bounded channel that holds at most `capacity` closures waiting to
+    /// execute on the JavaScript main thread.
+    ///
+    /// When the channel is full, [`Channel::try_send`] returns [`TrySendError::Full`]
+    /// and [`Channel::send`] blocks until a slot is available.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is `0`. Use [`Channel::new`] for an unbounded channel.
+    pub fn with_capacity<'a, C: Context<'a>>(cx: &mut C, capacity: usize) -> Self {
+        assert!(capacity > 0, "Channel capacity must be greater than zero");
+
+        Self {
+            state: Arc::new(ChannelState::new(cx, None, Some(capacity))),
+            has_ref: true,
+        }
+    }
+
+    /// Creates an unbounded channel with a `name` that identifies it in diagnostics.
+    ///
+    /// The name is used as the type of the channel's async resource, so it is reported
+    /// by [`async_hooks`](https://nodejs.org/api/async_hooks.html) and other diagnostic
+    /// tools. This can help find which channel scheduled a slow or stuck closure.
+    /// Channels created with [`Channel::new`] are named `"neon threadsafe function"`.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn start_worker(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    ///     let channel = Channel::named(&mut cx, "my-addon:worker");
+    ///
+    ///     std::thread::spawn(move || {
+    ///         channel.send(|_| Ok(()));
+    ///     });
+    ///
+    ///     Ok(cx.undefined())
+    /// }
+    /// ```
+    pub fn named<'a, C: Context<'a>>(cx: &mut C, name: &str) -> Self {
+        Self {
+            state: Arc::new(ChannelState::new(cx, Some(name), None)),
             has_ref: true,
         }
     }
 
     /// Allow the Node event loop to exit while this `Channel` exists.
     /// _Idempotent_
+    ///
+    /// Closures that have already been sent will still execute if the event loop is
+    /// otherwise kept alive. Requiring a [`Context`] ensures this can only be called
+    /// from the JavaScript thread.
     pub fn unref<'a, C: Context<'a>>(&mut self, cx: &mut C) -> &mut Self {
         // Already unreferenced
         if !self.has_ref {
@@ -125,6 +179,8 @@ impl Channel {
 
     /// Prevent the Node event loop from exiting while this `Channel` exists. (Default)
     /// _Idempotent_
+    ///
+    /// Like [`Channel::unref`], this may only be called from the JavaScript thread.
     pub fn reference<'a, C: Context<'a>>(&mut self, cx: &mut C) -> &mut Self {
         // Already referenced
         if self.has_ref {
@@ -138,25 +194,197 @@ impl Channel {
 
     /// Schedules a closure to execute on the JavaScript thread that created this Channel
     /// Panics if there is a libuv error
+    ///
+    /// If the channel is bounded and full, this blocks the current thread until
+    /// space is available. Calling `send` on a full channel from the JavaScript
+    /// main thread will deadlock; use [`Channel::try_send`] instead.
     pub fn send<T, F>(&self, f: F) -> JoinHandle<T>
     where
         T: Send + 'static,
         F: FnOnce(Cx) -> NeonResult<T> + Send + 'static,
     {
-        self.try_send(f).unwrap()
+        self.send_with_priority(Priority::Normal, f)
+    }
+
+    /// Schedules a boxed closure to execute on the JavaScript thread that created this
+    /// Channel. Panics if there is a libuv error
+    ///
+    /// [`Channel::send`] is generic and is instantiated for each distinct closure type,
+    /// which can increase compile times and binary size for addons with many call sites.
+    /// `send_boxed` is instantiated once for all closures, at the cost of an extra
+    /// allocation for the `Box`.
+    ///
+    /// Like [`Channel::send`], this blocks the current thread until space is available
+    /// if the channel is bounded and full.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn log(channel: &Channel, msg: String) {
+    ///     channel.send_boxed(Box::new(move |mut cx| {
+    ///         let console = cx.global_object().prop(&mut cx, "console").get::<JsObject>()?;
+    ///
+    ///         console.method(&mut cx, "log")?.arg(msg)?.exec()
+    ///     }));
+    /// }
+    /// ```
+    pub fn send_boxed(
+        &self,
+        f: Box<dyn FnOnce(Cx) -> NeonResult<()> + Send + 'static>,
+    ) -> JoinHandle<()> {
+        self.send(f)
+    }
+
+    /// Schedules a closure to execute on the JavaScript thread that created this Channel
+    /// ahead of any waiting closures with a lower [`Priority`].
+    /// Panics if there is a libuv error
+    ///
+    /// Closures waiting to execute are drained in priority order regardless of the
+    /// order they were sent. Ordering among closures of equal priority remains FIFO.
+    /// [`Channel::send`] and [`Channel::try_send`] use [`Priority::Normal`].
+    ///
+    /// Like [`Channel::send`], this blocks the current thread until space is available
+    /// if the channel is bounded and full.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// use neon::event::Priority;
+    ///
+    /// fn report(channel: &Channel, progress: f64, result: f64) {
+    ///     channel.send_with_priority(Priority::Low, move |mut cx| {
+    ///         let progress = cx.number(progress);
+    ///
+    ///         cx.global_object().prop(&mut cx, "progress").set(progress)?;
+    ///
+    ///         Ok(())
+    ///     });
+    ///
+    ///     // Executes before the progress update if neither has started
+    ///     channel.send_with_priority(Priority::High, move |mut cx| {
+    ///         let result = cx.number(result);
+    ///
+    ///         cx.global_object().prop(&mut cx, "result").set(result)?;
+    ///
+    ///         Ok(())
+    ///     });
+    /// }
+    /// ```
+    pub fn send_with_priority<T, F>(&self, priority: Priority, f: F) -> JoinHandle<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(Cx) -> NeonResult<T> + Send + 'static,
+    {
+        let slot = self.state.backlog.reserve();
+
+        self.schedule(Some(slot), priority, f).unwrap()
     }
 
     /// Schedules a closure to execute on the JavaScript thread that created this Channel
     /// Returns an `Error` if the task could not be scheduled.
     ///
-    /// See [`SendError`] for additional details on failure causes.
-    pub fn try_send<T, F>(&self, f: F) -> Result<JoinHandle<T>, SendError>
+    /// Unlike [`Channel::send`], this never blocks. If the channel is full, the closure
+    /// is handed back in [`TrySendError::Full`] so that the caller may retry or drop it.
+    ///
+    /// See [`TrySendError`] for additional details on failure causes.
+    pub fn try_send<T, F>(&self, f: F) -> Result<JoinHandle<T>, TrySendError<F>>
+    where
+        T: Send + 'static,
+        F: FnOnce(Cx) -> NeonResult<T> + Send + 'static,
+    {
+        let slot = match self.state.backlog.try_reserve() {
+            Some(slot) => slot,
+            None => return Err(TrySendError::Full(f)),
+        };
+
+        self.schedule(Some(slot), Priority::Normal, f)
+            .map_err(|_| TrySendError::Closed)
+    }
+
+    /// Executes a closure immediately on the JavaScript thread that created this
+    /// Channel, returning its result.
+    ///
+    /// The closure runs in a new handle scope of `cx` before `send_local` returns, so
+    /// an exception it throws propagates to the caller like any other Neon call. It
+    /// does not wait for closures that were already sent, and does not count towards
+    /// the capacity of a bounded channel.
+    ///
+    /// Throws an `Error` if called from any other JavaScript thread, e.g. a worker.
+    /// Calling [`Channel::send`] from the JavaScript thread, including from closures
+    /// executing on the channel, always schedules the closure to execute later.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn report(cx: &mut Cx, channel: &Channel, n: f64) -> NeonResult<()> {
+    ///     // On the JavaScript thread, log immediately instead of waiting for a tick
+    ///     channel.send_local(cx, |mut cx| {
+    ///         let console = cx.global::<JsObject>("console")?;
+    ///
+    ///         console.method(&mut cx, "log")?.arg(n)?.exec()
+    ///     })
+    /// }
+    /// ```
+    pub fn send_local<T, F>(&self, cx: &mut Cx, f: F) -> NeonResult<T>
+    where
+        F: FnOnce(Cx) -> NeonResult<T>,
+    {
+        if thread::current().id() != self.state.thread {
+            return cx.throw_error(
+                "Channel::send_local must be called on the JavaScript thread that created the Channel",
+            );
+        }
+
+        cx.execute_scoped(f)
+    }
+
+    /// Creates a [`CoalescingSender`] that executes `f` on the JavaScript thread with the
+    /// most recent value passed to [`CoalescingSender::set`].
+    ///
+    /// Values set while a previous value is still waiting replace it instead of scheduling
+    /// another closure, so `f` runs at most once per event loop tick with the latest value.
+    /// This is useful for reporting progress from a tight loop without flooding the
+    /// event loop.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn process(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    ///     let on_progress = cx.argument::<JsFunction>(0)?.root(&mut cx);
+    ///     let progress = cx.channel().coalescing(move |mut cx, percent: f64| {
+    ///         on_progress.to_inner(&mut cx).bind(&mut cx).arg(percent)?.exec()
+    ///     });
+    ///
+    ///     std::thread::spawn(move || {
+    ///         for i in 0..=100 {
+    ///             // Do some work...
+    ///             progress.set(i as f64);
+    ///         }
+    ///     });
+    ///
+    ///     Ok(cx.undefined())
+    /// }
+    /// ```
+    pub fn coalescing<T, F>(&self, f: F) -> CoalescingSender<T>
+    where
+        T: Send + 'static,
+        F: FnMut(Cx, T) -> NeonResult<()> + Send + 'static,
+    {
+        CoalescingSender {
+            channel: self.clone(),
+            state: Arc::new(Coalescing {
+                latest: Mutex::new(None),
+                f: Mutex::new(Box::new(f)),
+            }),
+        }
+    }
+
+    // Creates a callback for the closure and a handle to join on its result. The `slot`,
+    // if any, is held by the callback and released once it has executed or been dropped.
+    fn callback<T, F>(&self, slot: Option<Slot>, f: F) -> (Callback, JoinHandle<T>)
     where
         T: Send + 'static,
         F: FnOnce(Cx) -> NeonResult<T> + Send + 'static,
     {
         let (tx, rx) = oneshot::channel();
         let callback = Box::new(move |env| {
+            let _slot = slot;
             let env = Env::from(env);
 
             // Note: It is sufficient to use `Cx` because
@@ -167,12 +395,44 @@ impl Channel {
             });
         });
 
-        self.state
+        let handle = JoinHandle {
+            rx,
+            thread: self.state.thread,
+        };
+
+        (callback, handle)
+    }
+
+    // Schedules the closure on the threadsafe function
+    fn schedule<T, F>(
+        &self,
+        slot: Option<Slot>,
+        priority: Priority,
+        f: F,
+    ) -> Result<JoinHandle<T>, SendError>
+    where
+        T: Send + 'static,
+        F: FnOnce(Cx) -> NeonResult<T> + Send + 'static,
+    {
+        let (callback, handle) = self.callback(slot, f);
+
+        let id = self.state.queue.push(priority, callback);
+
+        // Each call on the threadsafe function executes the highest priority closure
+        // waiting, which is not necessarily the closure pushed above.
+        let queue = Arc::clone(&self.state.queue);
+        let result = self
+            .state
             .tsfn
-            .call(callback, None)
-            .map_err(|_| SendError)?;
+            .call(Box::new(move |env| queue.pop_and_call(env)), None);
+
+        // If the call failed, nothing will execute the closure. Remove and drop it to release
+        // its slot. If it is missing, another call has already taken it and it will execute.
+        if result.is_err() && self.state.queue.remove(priority, id).is_some() {
+            return Err(SendError);
+        }
 
-        Ok(JoinHandle { rx })
+        Ok(handle)
     }
 
     /// Returns a boolean indicating if this `Channel` will prevent the Node event
@@ -180,6 +440,25 @@ impl Channel {
     pub fn has_ref(&self) -> bool {
         self.has_ref
     }
+
+    /// Returns the number of closures sent on this `Channel`, or any of its clones,
+    /// that have not finished executing.
+    ///
+    /// Closures executed immediately by [`Channel::send_local`] are not counted. The
+    /// value may be read from any thread, but is immediately outdated if other threads
+    /// are sending.
+    pub fn pending_len(&self) -> usize {
+        self.state.backlog.len.load(Ordering::Acquire)
+    }
+
+    /// Returns the largest [`pending_len`](Channel::pending_len) observed since the
+    /// `Channel` was created.
+    ///
+    /// Comparing the high-water mark to the capacity of a bounded channel can help
+    /// detect a stalled JavaScript thread or tune the number of sending threads.
+    pub fn high_water_mark(&self) -> usize {
+        self.state.backlog.high_water_mark.load(Ordering::Acquire)
+    }
 }
 
 impl Clone for Channel {
@@ -238,20 +517,106 @@ impl Drop for Channel {
         // UV thread if strong reference count goes to 0.
         let state = Arc::clone(&self.state);
 
-        // `Channel::try_send` will only fail if the environment has shutdown.
-        // In that case, the teardown will perform clean-up.
-        let _ = self.try_send(move |mut cx| {
+        // `Channel::schedule` will only fail if the environment has shutdown.
+        // In that case, the teardown will perform clean-up. This bypasses the
+        // capacity limit since blocking in `Drop` could deadlock.
+        let _ = self.schedule(None, Priority::Normal, move |mut cx| {
             state.unref(&mut cx);
             Ok(())
         });
     }
 }
 
+/// Sender that coalesces rapid updates into a single closure executing on the JavaScript
+/// thread. Created with [`Channel::coalescing`].
+///
+/// Cloning a `CoalescingSender` creates a sender that shares the same pending value.
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-4")))]
+pub struct CoalescingSender<T> {
+    channel: Channel,
+    state: Arc<Coalescing<T>>,
+}
+
+type CoalescingCallback<T> = Box<dyn FnMut(Cx, T) -> NeonResult<()> + Send + 'static>;
+
+struct Coalescing<T> {
+    // The value waiting to be passed to `f`. `Some` only while a closure is scheduled.
+    latest: Mutex<Option<T>>,
+    f: Mutex<CoalescingCallback<T>>,
+}
+
+impl<T: Send + 'static> CoalescingSender<T> {
+    /// Sets the value passed to the next execution of the closure, replacing any value
+    /// that is still waiting.
+    /// Panics if there is a libuv error
+    pub fn set(&self, value: T) {
+        let previous = self
+            .state
+            .latest
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .replace(value);
+
+        // A closure is already scheduled and will read the new value
+        if previous.is_some() {
+            return;
+        }
+
+        let state = Arc::clone(&self.state);
+
+        self.channel.send(move |cx| {
+            let latest = state
+                .latest
+                .lock()
+                .unwrap_or_else(|err| err.into_inner())
+                .take();
+
+            match latest {
+                Some(latest) => (state.f.lock().unwrap_or_else(|err| err.into_inner()))(cx, latest),
+                None => Ok(()),
+            }
+        });
+    }
+}
+
+impl<T> Clone for CoalescingSender<T> {
+    fn clone(&self) -> Self {
+        Self {
+            channel: self.channel.clone(),
+            state: Arc::clone(&self.state),
+        }
+    }
+}
+
+impl<T> fmt::Debug for CoalescingSender<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("CoalescingSender")
+    }
+}
+
+/// Priority of a closure sent with [`Channel::send_with_priority`].
+///
+/// Closures with a higher priority execute before closures with a lower priority
+/// that are still waiting, regardless of the order they were sent.
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-4")))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Priority {
+    /// Executes before `Normal` and `Low` priority closures
+    High,
+    /// The priority of closures sent with [`Channel::send`] (Default)
+    #[default]
+    Normal,
+    /// Executes after `High` and `Normal` priority closures
+    Low,
+}
+
 /// An owned permission to join on the result of a closure sent to the JavaScript main
 /// thread with [`Channel::send`].
 pub struct JoinHandle<T> {
     // `Err` is always `Throw`, but `Throw` cannot be sent across threads
     rx: oneshot::Receiver<Result<T, SendThrow>>,
+    // JavaScript thread that executes the closure
+    thread: ThreadId,
 }
 
 impl<T> JoinHandle<T> {
@@ -259,10 +624,18 @@ impl<T> JoinHandle<T> {
     ///
     /// If the closure panics or throws an exception, `Err` is returned
     ///
+    /// Since the closure can only execute once control returns to the event loop,
+    /// joining from the JavaScript thread that owns the [`Channel`] would never
+    /// complete. In that case, `Err` is returned immediately instead of blocking.
+    ///
     /// # Panics
     ///
     /// This function panics if called within an asynchronous execution context.
     pub fn join(self) -> Result<T, JoinError> {
+        if thread::current().id() == self.thread {
+            return Err(JoinError(JoinErrorType::Deadlock));
+        }
+
         Ok(self.rx.blocking_recv()??)
     }
 }
@@ -290,13 +663,14 @@ impl<T> Future for JoinHandle<T> {
 
 #[derive(Debug)]
 /// Error returned by [`JoinHandle::join`] indicating the associated closure panicked
-/// or threw an exception.
+/// or threw an exception, or that joining would have deadlocked the JavaScript thread.
 pub struct JoinError(JoinErrorType);
 
 #[derive(Debug)]
 enum JoinErrorType {
     Panic,
     Throw,
+    Deadlock,
 }
 
 impl JoinError {
@@ -304,6 +678,7 @@ impl JoinError {
         match &self.0 {
             JoinErrorType::Panic => "Closure panicked before returning",
             JoinErrorType::Throw => "Closure threw an exception",
+            JoinErrorType::Deadlock => "Cannot join a closure from the JavaScript thread",
         }
     }
 }
@@ -348,9 +723,6 @@ impl<T> ResultExt<T> for Result<T, JoinError> {
 /// The most likely cause of a failure is that Node is shutting down. This may occur if the
 /// process is forcefully exiting even if the channel is referenced. For example, by calling
 /// `process.exit()`.
-//
-// NOTE: These docs will need to be updated to include `QueueFull` if bounded queues are
-// implemented.
 #[cfg_attr(docsrs, doc(cfg(feature = "napi-4")))]
 pub struct SendError;
 
@@ -368,17 +740,79 @@ impl fmt::Debug for SendError {
 
 impl error::Error for SendError {}
 
+/// Error returned by [`Channel::try_send`] indicating that a closure was not scheduled.
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-4")))]
+pub enum TrySendError<F> {
+    /// The channel is at capacity. The closure is returned so that it may be retried
+    /// or dropped.
+    Full(F),
+    /// The closure could not be scheduled on the event loop. See [`SendError`] for
+    /// details.
+    Closed,
+}
+
+impl<F> TrySendError<F> {
+    /// Returns `true` if the channel was at capacity
+    pub fn is_full(&self) -> bool {
+        matches!(self, Self::Full(_))
+    }
+
+    /// Returns the closure if the channel was at capacity
+    pub fn into_inner(self) -> Option<F> {
+        match self {
+            Self::Full(f) => Some(f),
+            Self::Closed => None,
+        }
+    }
+}
+
+impl<F> fmt::Display for TrySendError<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Full(_) => write!(f, "TrySendError::Full"),
+            Self::Closed => write!(f, "TrySendError::Closed"),
+        }
+    }
+}
+
+impl<F> fmt::Debug for TrySendError<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl<F> error::Error for TrySendError<F> {}
+
+impl<F> From<TrySendError<F>> for SendError {
+    fn from(_: TrySendError<F>) -> Self {
+        SendError
+    }
+}
+
 struct ChannelState {
     tsfn: ThreadsafeFunction<Callback>,
     ref_count: AtomicUsize,
+    backlog: Arc<Backlog>,
+    queue: Arc<PriorityQueue>,
+    // The JavaScript thread that created the channel and executes its closures
+    thread: ThreadId,
 }
 
 impl ChannelState {
-    fn new<'a, C: Context<'a>>(cx: &mut C) -> Self {
-        let tsfn = unsafe { ThreadsafeFunction::new(cx.env().to_raw(), Self::callback) };
+    fn new<'a, C: Context<'a>>(cx: &mut C, name: Option<&str>, capacity: Option<usize>) -> Self {
+        let env = cx.env().to_raw();
+        let tsfn = unsafe {
+            match name {
+                Some(name) => ThreadsafeFunction::named(env, name, Self::callback),
+                None => ThreadsafeFunction::new(env, Self::callback),
+            }
+        };
         Self {
             tsfn,
             ref_count: AtomicUsize::new(1),
+            backlog: Arc::new(Backlog::new(capacity)),
+            queue: Arc::new(PriorityQueue::default()),
+            thread: thread::current().id(),
         }
     }
 
@@ -417,3 +851,130 @@ impl ChannelState {
         }
     }
 }
+
+// Closures waiting to execute, grouped by `Priority`. N-API threadsafe functions are
+// FIFO, so closures are buffered here and the threadsafe function only signals that
+// one should be executed.
+#[derive(Default)]
+struct PriorityQueue {
+    queues: Mutex<[VecDeque<(u64, Callback)>; 3]>,
+    // Identifies closures so that they can be removed if scheduling fails
+    next_id: AtomicU64,
+}
+
+impl PriorityQueue {
+    fn push(&self, priority: Priority, callback: Callback) -> u64 {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let mut queues = self.queues.lock().unwrap_or_else(|err| err.into_inner());
+
+        queues[priority as usize].push_back((id, callback));
+
+        id
+    }
+
+    // Removes a closure that has not been executed
+    fn remove(&self, priority: Priority, id: u64) -> Option<Callback> {
+        let mut queues = self.queues.lock().unwrap_or_else(|err| err.into_inner());
+        let queue = &mut queues[priority as usize];
+        let index = queue.iter().position(|(i, _)| *i == id)?;
+
+        queue.remove(index).map(|(_, callback)| callback)
+    }
+
+    // Executes the highest priority closure waiting. The lock is released before
+    // calling so that the closure may send on the channel.
+    fn pop_and_call(&self, env: sys::Env) {
+        let callback = {
+            let mut queues = self.queues.lock().unwrap_or_else(|err| err.into_inner());
+
+            queues.iter_mut().find_map(VecDeque::pop_front)
+        };
+
+        if let Some((_, callback)) = callback {
+            callback(env);
+        }
+    }
+}
+
+// Tracks the number of closures waiting to execute. The limit is enforced by Neon
+// instead of the N-API queue size so that internal closures (e.g., unref on drop)
+// are never blocked.
+struct Backlog {
+    // `None` is unbounded
+    capacity: Option<usize>,
+    len: AtomicUsize,
+    high_water_mark: AtomicUsize,
+    lock: Mutex<()>,
+    available: Condvar,
+}
+
+impl Backlog {
+    fn new(capacity: Option<usize>) -> Self {
+        Self {
+            capacity,
+            len: AtomicUsize::new(0),
+            high_water_mark: AtomicUsize::new(0),
+            lock: Mutex::new(()),
+            available: Condvar::new(),
+        }
+    }
+
+    // Reserve a slot without blocking, returning `None` if the backlog is full
+    fn try_reserve(self: &Arc<Self>) -> Option<Slot> {
+        let len = self
+            .len
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |len| {
+                let full = matches!(self.capacity, Some(capacity) if len >= capacity);
+
+                (!full).then_some(len + 1)
+            })
+            .ok()?;
+
+        self.high_water_mark.fetch_max(len + 1, Ordering::AcqRel);
+
+        Some(Slot(Arc::clone(self)))
+    }
+
+    // Reserve a slot, blocking until one is available
+    fn reserve(self: &Arc<Self>) -> Slot {
+        if let Some(slot) = self.try_reserve() {
+            return slot;
+        }
+
+        // Holding the lock while checking prevents missing a notification between
+        // a failed reservation and waiting
+        let mut guard = self.lock.lock().unwrap_or_else(|err| err.into_inner());
+
+        loop {
+            if let Some(slot) = self.try_reserve() {
+                return slot;
+            }
+
+            guard = self
+                .available
+                .wait(guard)
+                .unwrap_or_else(|err| err.into_inner());
+        }
+    }
+
+    fn release(&self) {
+        self.len.fetch_sub(1, Ordering::AcqRel);
+
+        if self.capacity.is_none() {
+            return;
+        }
+
+        let _guard = self.lock.lock().unwrap_or_else(|err| err.into_inner());
+
+        self.available.notify_one();
+    }
+}
+
+// A reserved position in the `Backlog`, released on drop
+struct Slot(Arc<Backlog>);
+
+impl Drop for Slot {
+    fn drop(&mut self) {
+        self.0.release();
+    }
+}
@@ -124,12 +124,14 @@ mod channel;
 
 mod task;
 
-pub use self::task::TaskBuilder;
+pub use self::task::{TaskBuilder, TaskHandle};
 
 #[cfg(all(feature = "napi-5", feature = "futures"))]
 pub(crate) use self::channel::SendThrow;
 #[cfg(feature = "napi-4")]
-pub use self::channel::{Channel, JoinError, JoinHandle, SendError};
+pub use self::channel::{
+    Channel, CoalescingSender, JoinError, JoinHandle, Priority, SendError, TrySendError,
+};
 
 #[cfg(feature = "napi-4")]
 #[deprecated(since = "0.9.0", note = "Please use the Channel type instead")]
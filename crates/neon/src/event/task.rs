@@ -3,9 +3,10 @@ use std::{panic::resume_unwind, thread};
 use crate::{
     context::{internal::Env, Context, Cx},
     handle::Handle,
+    object::Object,
     result::{JsResult, NeonResult},
     sys::{async_work, raw},
-    types::{Deferred, JsPromise, Value},
+    types::{Deferred, JsError, JsPromise, Value},
 };
 
 /// Node asynchronous task builder
@@ -59,6 +60,34 @@ where
     /// is passed the return value from `execute`. If the `complete` callback
     /// throws, the promise will be rejected with the exception
     pub fn promise<V, F>(self, complete: F) -> Handle<'a, JsPromise>
+    where
+        V: Value,
+        F: FnOnce(Cx, O) -> JsResult<V> + 'static,
+    {
+        self.cancellable_promise(complete).0
+    }
+
+    /// Schedules a task like [`TaskBuilder::promise`], additionally returning a
+    /// [`TaskHandle`] that can cancel the task.
+    ///
+    /// If the task is cancelled, the `complete` callback is not called and the promise
+    /// is rejected with an `AbortError`.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// # fn compute() -> f64 { 42.0 }
+    /// fn compute_unless_aborted(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    ///     let (promise, task) = cx
+    ///         .task(compute)
+    ///         .cancellable_promise(|mut cx, n| Ok(cx.number(n)));
+    ///
+    ///     // Cancelled if it has not started executing on the worker pool
+    ///     task.cancel(&mut cx);
+    ///
+    ///     Ok(promise)
+    /// }
+    /// ```
+    pub fn cancellable_promise<V, F>(self, complete: F) -> (Handle<'a, JsPromise>, TaskHandle)
     where
         V: Value,
         F: FnOnce(Cx, O) -> JsResult<V> + 'static,
@@ -66,10 +95,23 @@ where
         let env = self.cx.env();
         let (deferred, promise) = JsPromise::new(self.cx);
         let execute = self.execute;
+        let work = schedule_promise(env, execute, complete, deferred);
 
-        schedule_promise(env, execute, complete, deferred);
+        (promise, TaskHandle(work))
+    }
+}
 
-        promise
+/// Handle to a task scheduled with [`TaskBuilder::cancellable_promise`]
+pub struct TaskHandle(async_work::Work);
+
+impl TaskHandle {
+    /// Attempts to cancel the task, returning `true` if it was cancelled.
+    ///
+    /// Cancellation only succeeds before the `execute` callback has started running
+    /// on the Node worker pool. Tasks that are executing or have completed are not
+    /// affected.
+    pub fn cancel<'a, C: Context<'a>>(&self, cx: &mut C) -> bool {
+        unsafe { self.0.cancel(cx.env().to_raw()) }
     }
 }
 
@@ -93,11 +135,16 @@ where
     input()
 }
 
-fn complete<O, D>(env: raw::Env, output: thread::Result<O>, callback: D)
+fn complete<O, D>(env: raw::Env, output: Option<thread::Result<O>>, callback: D)
 where
     O: Send + 'static,
     D: FnOnce(Cx, O) -> NeonResult<()> + 'static,
 {
+    // Tasks scheduled with `and_then` cannot be cancelled
+    let Some(output) = output else {
+        return;
+    };
+
     let output = output.unwrap_or_else(|panic| {
         // If a panic was caught while executing the task on the Node Worker
         // pool, resume panicking on the main JavaScript thread
@@ -110,7 +157,12 @@ where
 }
 
 // Schedule a task to execute on the Node worker pool and settle a `Promise` with the result
-fn schedule_promise<I, O, D, V>(env: Env, input: I, complete: D, deferred: Deferred)
+fn schedule_promise<I, O, D, V>(
+    env: Env,
+    input: I,
+    complete: D,
+    deferred: Deferred,
+) -> async_work::Work
 where
     I: FnOnce() -> O + Send + 'static,
     O: Send + 'static,
@@ -124,13 +176,13 @@ where
             execute::<I, O>,
             complete_promise::<O, D, V>,
             (complete, deferred),
-        );
+        )
     }
 }
 
 fn complete_promise<O, D, V>(
     env: raw::Env,
-    output: thread::Result<O>,
+    output: Option<thread::Result<O>>,
     (complete, deferred): (D, Deferred),
 ) where
     O: Send + 'static,
@@ -140,7 +192,15 @@ fn complete_promise<O, D, V>(
     let env = env.into();
 
     Cx::with_context(env, move |cx| {
-        deferred.try_catch_settle(cx, move |cx| {
+        deferred.try_catch_settle(cx, move |mut cx| {
+            let Some(output) = output else {
+                let err = JsError::error_with_code(&mut cx, "ABORT_ERR", "The task was aborted")?;
+
+                err.prop(&mut cx, "name").set("AbortError")?;
+
+                return cx.throw(err);
+            };
+
             let output = output.unwrap_or_else(|panic| resume_unwind(panic));
 
             complete(cx, output)
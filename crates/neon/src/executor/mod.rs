@@ -1,6 +1,15 @@
-use std::{future::Future, pin::Pin};
+use std::{cell::Cell, future::Future, pin::Pin};
 
-use crate::{context::Cx, thread::LocalKey};
+use ::tokio::sync::mpsc;
+
+use crate::{
+    context::{Context, Cx},
+    event::Channel,
+    object::Object,
+    result::JsResult,
+    thread::LocalKey,
+    types::{extract::TryIntoJs, Deferred, JsFunction, JsObject, JsSymbol},
+};
 
 #[cfg(feature = "tokio-rt")]
 pub(crate) mod tokio;
@@ -13,10 +22,35 @@ pub trait Runtime: Send + Sync + 'static {
     fn spawn(&self, fut: BoxFuture);
 }
 
+/// Any spawn function can be used as a runtime, e.g. `|fut| { async_std::task::spawn(fut); }`
+impl<F> Runtime for F
+where
+    F: Fn(BoxFuture) + Send + Sync + 'static,
+{
+    fn spawn(&self, fut: BoxFuture) {
+        self(fut)
+    }
+}
+
 /// Register a [`Future`] executor runtime globally to the addon.
 ///
 /// Returns `Ok(())` if a global executor has not been set and `Err(runtime)` if it has.
 ///
+/// The runtime may be a tokio `Runtime` or `Handle`, or a closure that spawns each future
+/// on another executor:
+///
+/// ```
+/// # use neon::prelude::*;
+/// # fn spawn<T>(_: T) {}
+/// #[neon::main]
+/// fn main(mut cx: ModuleContext) -> NeonResult<()> {
+///     // e.g., `async_std::task::spawn`
+///     let _ = neon::set_global_executor(&mut cx, |fut| spawn(fut));
+///
+///     Ok(())
+/// }
+/// ```
+///
 /// If the `tokio` feature flag is enabled and the addon does not provide a
 /// [`#[neon::main]`](crate::main) function, a multithreaded tokio runtime will be
 /// automatically registered.
@@ -59,3 +93,67 @@ where
 
     Ok(())
 }
+
+// Creates an async iterator where each call to `next()` receives a value from `rx`
+pub(crate) fn async_iterator<'cx, T>(
+    cx: &mut Cx<'cx>,
+    rx: mpsc::Receiver<T>,
+) -> JsResult<'cx, JsObject>
+where
+    T: for<'a> TryIntoJs<'a> + Send + 'static,
+{
+    // Calls to `next()` may overlap. A single driver task owns `rx` and settles requests
+    // in the order they were made.
+    let (requests, pending) = mpsc::unbounded_channel::<(Deferred, Channel)>();
+    let driver = Cell::new(Some((rx, pending)));
+    let next = JsFunction::new(cx, move |mut cx| {
+        let rt = match RUNTIME.get(&mut cx) {
+            Some(rt) => rt,
+            None => return cx.throw_error("must initialize with neon::set_global_executor"),
+        };
+
+        // The driver is started by the first call to `next()`
+        if let Some((rx, pending)) = driver.take() {
+            rt.spawn(Box::pin(drive(rx, pending)));
+        }
+
+        let ch = cx.channel();
+        let (d, promise) = cx.promise();
+
+        // The driver only exits after `requests` is dropped
+        let _ = requests.send((d, ch));
+
+        Ok(promise)
+    })?;
+
+    let iterator = cx.empty_object();
+    let symbol = JsSymbol::async_iterator(cx)?;
+    let this = JsFunction::new(cx, |mut cx| Ok(cx.this_value()))?;
+
+    iterator.prop(cx, "next").set(next)?;
+    iterator.prop(cx, symbol).set(this)?;
+
+    Ok(iterator)
+}
+
+async fn drive<T>(
+    mut rx: mpsc::Receiver<T>,
+    mut pending: mpsc::UnboundedReceiver<(Deferred, Channel)>,
+) where
+    T: for<'a> TryIntoJs<'a> + Send + 'static,
+{
+    while let Some((d, ch)) = pending.recv().await {
+        let value = rx.recv().await;
+        let _ = d.try_settle_with(&ch, move |mut cx| {
+            let result = cx.empty_object();
+
+            result
+                .prop(&mut cx, "done")
+                .set(value.is_none())?
+                .prop("value")
+                .set(value)?;
+
+            Ok(result)
+        });
+    }
+}
@@ -4,7 +4,7 @@
 //! by the JavaScript engine's memory management system (the garbage collector).
 //!
 //! Neon APIs that accept and return JavaScript values never use raw pointer types
-//! ([`*T`](pointer)) or reference types ([`&T`](reference)). Instead they use the
+//! ([`*T`](pointer)) or reference types ([`&T`](prim@reference)). Instead they use the
 //! special Neon type [`Handle`], which encapsulates a JavaScript
 //! [`Value`] and ensures that Rust only maintains access to
 //! the value while it is guaranteed to be valid.
@@ -46,6 +46,9 @@ pub(crate) mod internal;
 
 pub(crate) mod root;
 
+#[cfg(feature = "napi-6")]
+pub(crate) mod reference;
+
 use std::{
     error::Error,
     fmt::{self, Debug, Display},
@@ -56,12 +59,17 @@ use std::{
 
 pub use self::root::Root;
 
+#[cfg(feature = "napi-6")]
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
+pub use self::reference::{Reference, Weak};
+
 use crate::{
-    context::Context,
+    context::{Context, Cx},
     handle::internal::{SuperType, TransparentNoCopyWrapper},
-    result::{JsResult, ResultExt},
+    object::Object,
+    result::{JsResult, NeonResult, ResultExt, Throw},
     sys,
-    types::Value,
+    types::{private::ValueInternal, JsFunction, JsObject, Value, ValueType},
 };
 
 /// A handle to a JavaScript value that is owned by the JavaScript engine.
@@ -128,6 +136,10 @@ impl<'a, F: Value, T: Value> ResultExt<Handle<'a, T>> for DowncastResult<'a, F,
     fn or_throw<'b, C: Context<'b>>(self, cx: &mut C) -> JsResult<'a, T> {
         match self {
             Ok(v) => Ok(v),
+            // The type check ran user code that threw, e.g. a `Symbol.hasInstance` method
+            Err(_) if unsafe { sys::error::is_throwing(cx.env().to_raw()) } => {
+                Err(unsafe { Throw::new() })
+            }
             Err(e) => cx.throw_type_error(e.to_string()),
         }
     }
@@ -159,6 +171,49 @@ impl<'a, T: Value> Handle<'a, T> {
         U::is_typeof(cx.env(), self.deref())
     }
 
+    /// Tests whether this value is `null` or `undefined`.
+    ///
+    /// # Example:
+    ///
+    /// ```no_run
+    /// # use neon::prelude::*;
+    /// # fn my_neon_function(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    /// let v: Handle<JsValue> = cx.null().upcast();
+    /// v.is_nullish(&mut cx); // true
+    /// # Ok(cx.undefined())
+    /// # }
+    /// ```
+    pub fn is_nullish<'b, C: Context<'b>>(&self, cx: &mut C) -> bool {
+        unsafe { sys::tag::is_nullish(cx.env().to_raw(), self.to_local()) }
+    }
+
+    /// Produces `None` if this value is `null` or `undefined`, and `Some` otherwise.
+    /// Useful for reading optional properties where JavaScript code uses `null` and
+    /// `undefined` interchangeably.
+    ///
+    /// # Example:
+    ///
+    /// ```no_run
+    /// # use neon::prelude::*;
+    /// # fn my_neon_function(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    /// let options: Handle<JsObject> = cx.argument(0)?;
+    /// let timeout: Handle<JsValue> = options.prop(&mut cx, "timeout").get()?;
+    ///
+    /// if let Some(timeout) = timeout.as_option(&mut cx) {
+    ///     let timeout = timeout.downcast_or_throw::<JsNumber, _>(&mut cx)?;
+    ///     # let _ = timeout;
+    /// }
+    /// # Ok(cx.undefined())
+    /// # }
+    /// ```
+    pub fn as_option<'b, C: Context<'b>>(&self, cx: &mut C) -> Option<Handle<'a, T>> {
+        if self.is_nullish(cx) {
+            None
+        } else {
+            Some(*self)
+        }
+    }
+
     /// Attempts to downcast a handle to another type, which may fail. A failure
     /// to downcast **does not** throw a JavaScript exception, so it's OK to
     /// continue interacting with the JS engine if this method produces an `Err`
@@ -177,6 +232,49 @@ impl<'a, T: Value> Handle<'a, T> {
         self.downcast(cx).or_throw(cx)
     }
 
+    /// Returns the type of this value, like the JavaScript `typeof` operator.
+    ///
+    /// Unlike `typeof`, `null` has its own [`ValueType::Null`]. This performs a single
+    /// type check, so it can be used to `match` on a value instead of attempting a
+    /// series of [`downcast`](Handle::downcast)s.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// use neon::types::ValueType;
+    ///
+    /// fn describe(mut cx: FunctionContext) -> JsResult<JsString> {
+    ///     let value = cx.argument::<JsValue>(0)?;
+    ///     let description = match value.type_of(&mut cx) {
+    ///         ValueType::Undefined | ValueType::Null => "nothing",
+    ///         ValueType::Number | ValueType::BigInt => "a number",
+    ///         ValueType::Function => "a function",
+    ///         _ => "something else",
+    ///     };
+    ///
+    ///     Ok(cx.string(description))
+    /// }
+    /// ```
+    pub fn type_of<'b, C: Context<'b>>(&self, cx: &mut C) -> ValueType {
+        let ty = unsafe { sys::tag::type_of(cx.env().to_raw(), self.to_local()) };
+
+        match ty {
+            sys::ValueType::Undefined => ValueType::Undefined,
+            sys::ValueType::Null => ValueType::Null,
+            sys::ValueType::Boolean => ValueType::Boolean,
+            sys::ValueType::Number => ValueType::Number,
+            sys::ValueType::String => ValueType::String,
+            sys::ValueType::Symbol => ValueType::Symbol,
+            sys::ValueType::Object => ValueType::Object,
+            sys::ValueType::Function => ValueType::Function,
+            sys::ValueType::External => ValueType::External,
+            sys::ValueType::BigInt => ValueType::BigInt,
+        }
+    }
+
+    /// Tests whether this value and `other` are identical, like the JavaScript `===`
+    /// operator.
+    ///
+    /// **See also:** [`Handle::same_value`]
     pub fn strict_equals<'b, U: Value, C: Context<'b>>(
         &self,
         cx: &mut C,
@@ -184,6 +282,139 @@ impl<'a, T: Value> Handle<'a, T> {
     ) -> bool {
         unsafe { sys::mem::strict_equals(cx.env().to_raw(), self.to_local(), other.to_local()) }
     }
+
+    /// Tests whether this value and `other` are the same value, like the JavaScript
+    /// [`Object.is`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/is)
+    /// function. Unlike [`strict_equals`](Handle::strict_equals), `NaN` is the same value
+    /// as `NaN`, and `+0` is not the same value as `-0`.
+    pub fn same_value<'b, U: Value, C: Context<'b>>(
+        &self,
+        cx: &mut C,
+        other: Handle<'b, U>,
+    ) -> bool {
+        let env = cx.env().to_raw();
+        let (a, b) = (self.to_local(), other.to_local());
+
+        unsafe {
+            if !sys::tag::is_number(env, a) || !sys::tag::is_number(env, b) {
+                return sys::mem::strict_equals(env, a, b);
+            }
+
+            let (a, b) = (
+                sys::primitive::number_value(env, a),
+                sys::primitive::number_value(env, b),
+            );
+
+            // Equal numbers have the same bits, except for `NaN`, which has many encodings
+            a.to_bits() == b.to_bits() || (a.is_nan() && b.is_nan())
+        }
+    }
+
+    /// Tests whether this value is an instance of `constructor`, like the JavaScript
+    /// `instanceof` operator. Unlike [`is_a`](Handle::is_a), this checks the prototype
+    /// chain, so it can distinguish instances of user-defined classes.
+    ///
+    /// # Example:
+    ///
+    /// ```no_run
+    /// # use neon::prelude::*;
+    /// # fn my_neon_function(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    /// let v: Handle<JsValue> = cx.argument(0)?;
+    /// let range_error: Handle<JsFunction> = cx.global("RangeError")?;
+    ///
+    /// if v.instance_of(&mut cx, range_error)? {
+    ///     // ...
+    /// }
+    /// # Ok(cx.undefined())
+    /// # }
+    /// ```
+    ///
+    /// Throws a `TypeError` if `constructor` cannot be used with `instanceof`, e.g. if
+    /// it does not have a `prototype` object. Exceptions thrown by a custom
+    /// `Symbol.hasInstance` method are propagated.
+    pub fn instance_of<'b, C: Context<'b>>(
+        &self,
+        cx: &mut C,
+        constructor: Handle<'b, JsFunction>,
+    ) -> NeonResult<bool> {
+        let env = cx.env().to_raw();
+        let mut result = false;
+
+        unsafe {
+            match sys::tag::instance_of(env, self.to_local(), constructor.to_local(), &mut result) {
+                sys::Status::Ok => Ok(result),
+                // `instanceof` itself threw, e.g. because `constructor` has no prototype
+                _ if sys::error::is_throwing(env) => Err(Throw::new()),
+                _ => cx.throw_type_error("constructor is not callable"),
+            }
+        }
+    }
+
+    /// Formats this value as a human-readable string for debugging, with the default
+    /// [`InspectOptions`].
+    ///
+    /// **See also:** [`Handle::to_debug_string_with`]
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn log_argument(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    ///     let value = cx.argument::<JsValue>(0)?;
+    ///
+    ///     println!("received {}", value.to_debug_string(&mut cx)?);
+    ///
+    ///     Ok(cx.undefined())
+    /// }
+    /// ```
+    pub fn to_debug_string(&self, cx: &mut Cx) -> NeonResult<String> {
+        self.to_debug_string_with(cx, InspectOptions::default())
+    }
+
+    /// Formats this value as a human-readable string for debugging.
+    ///
+    /// The value is formatted with Node.js [`util.inspect`](https://nodejs.org/api/util.html#utilinspectobject-options),
+    /// which includes nested objects up to `options.depth`. On versions of Node.js
+    /// without [`process.getBuiltinModule`](https://nodejs.org/api/process.html#processgetbuiltinmoduleid),
+    /// the value is converted with `String(value)` instead and `options` are ignored.
+    pub fn to_debug_string_with(&self, cx: &mut Cx, options: InspectOptions) -> NeonResult<String> {
+        let value = self.as_value(cx);
+        let process: Handle<JsObject> = cx.global("process")?;
+        let get_builtin_module: Option<Handle<JsFunction>> =
+            process.prop(cx, "getBuiltinModule").get()?;
+
+        let Some(get_builtin_module) = get_builtin_module else {
+            let string: Handle<JsFunction> = cx.global("String")?;
+
+            return string.bind(cx).arg(value)?.call();
+        };
+
+        let util: Handle<JsObject> = get_builtin_module.bind(cx).arg("util")?.call()?;
+        let opts = cx.empty_object();
+
+        // `null` formats nested objects without a depth limit
+        opts.prop(cx, "depth").set(options.depth.map(f64::from))?;
+        opts.prop(cx, "colors").set(options.colors)?;
+
+        util.method(cx, "inspect")?.arg(value)?.arg(opts)?.call()
+    }
+}
+
+/// Options for formatting a value with [`Handle::to_debug_string_with`].
+#[derive(Clone, Debug)]
+pub struct InspectOptions {
+    /// The number of levels of nested objects to format, or `None` for no limit.
+    /// Defaults to `Some(2)`.
+    pub depth: Option<u32>,
+    /// Whether to style the output with ANSI color codes. Defaults to `false`.
+    pub colors: bool,
+}
+
+impl Default for InspectOptions {
+    fn default() -> Self {
+        Self {
+            depth: Some(2),
+            colors: false,
+        }
+    }
 }
 
 impl<'a, V: Value> Deref for Handle<'a, V> {
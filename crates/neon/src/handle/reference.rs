@@ -0,0 +1,237 @@
+use std::{marker::PhantomData, sync::Arc};
+
+use crate::{
+    context::Context,
+    handle::{
+        root::{instance_id, NapiRef},
+        Handle,
+    },
+    lifecycle::{DropData, InstanceData, InstanceId},
+    object::Object,
+    sys::{reference, tsfn::ThreadsafeFunction},
+    types::boxed::Finalize,
+};
+
+/// A reference to a JavaScript object with a manually managed reference count.
+///
+/// `Reference<T>` is a low-level alternative to [`Root<T>`](super::Root) that maps
+/// directly to N-API [references](https://nodejs.org/api/n-api.html#references-to-values-with-a-lifespan-longer-than-that-of-the-native-method).
+/// While the count is greater than zero, the object will not be garbage collected. When
+/// the count is zero, the reference is _weak_: the object may be collected, after which
+/// [`Reference::get`] returns `None`.
+///
+/// The count is only changed by [`Reference::ref_`] and [`Reference::unref`]. Dropping
+/// a `Reference` deletes the N-API reference regardless of its count. Like `Root`, a
+/// `Reference` may be sent across threads, but may only be used on the JavaScript thread
+/// that created it.
+///
+/// ```
+/// # use neon::prelude::*;
+/// use neon::handle::Reference;
+///
+/// fn is_alive(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+///     let obj = cx.argument::<JsObject>(0)?;
+///
+///     // A weak reference does not keep `obj` alive
+///     let weak = Reference::new(&mut cx, obj, 0);
+///     let alive = weak.get(&mut cx).is_some();
+///
+///     weak.delete(&mut cx);
+///
+///     Ok(cx.boolean(alive))
+/// }
+/// ```
+pub struct Reference<T> {
+    // `None` once the reference has been deleted with `Reference::delete`
+    internal: Option<NapiRef>,
+    instance_id: InstanceId,
+    drop_queue: Arc<ThreadsafeFunction<DropData>>,
+    _phantom: PhantomData<T>,
+}
+
+impl<T> std::fmt::Debug for Reference<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Reference<{}>", std::any::type_name::<T>())
+    }
+}
+
+// Safety: Like `Root`, access to the `NapiRef` is serialized by obtaining a `Context`
+unsafe impl<T> Send for Reference<T> {}
+
+unsafe impl<T> Sync for Reference<T> {}
+
+impl<T: Object> Reference<T> {
+    /// Creates a reference to a JavaScript object with an initial reference `count`.
+    /// A `count` of `0` creates a weak reference.
+    pub fn new<'a, C: Context<'a>>(cx: &mut C, value: Handle<T>, count: u32) -> Self {
+        let env = cx.env().to_raw();
+        let internal = unsafe { reference::new(env, value.to_local(), count) };
+
+        Self {
+            internal: Some(NapiRef(internal.cast())),
+            instance_id: instance_id(cx),
+            drop_queue: InstanceData::drop_queue(cx),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Increments the reference count, returning the new count. Incrementing the count
+    /// of a weak reference makes it strong again, if the object has not been collected.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if it is called from a different JavaScript thread than the
+    /// one in which the reference was created.
+    pub fn ref_<'a, C: Context<'a>>(&self, cx: &mut C) -> u32 {
+        let env = cx.env().to_raw();
+        let internal = self.as_napi_ref(cx).0.cast();
+
+        unsafe { reference::reference(env, internal) as u32 }
+    }
+
+    /// Decrements the reference count, returning the new count. The reference becomes
+    /// weak when the count reaches zero, but is not deleted.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the count is already zero or if it is called from a
+    /// different JavaScript thread than the one in which the reference was created.
+    pub fn unref<'a, C: Context<'a>>(&self, cx: &mut C) -> u32 {
+        let env = cx.env().to_raw();
+        let internal = self.as_napi_ref(cx).0.cast();
+
+        unsafe { reference::decrement(env, internal) }
+    }
+
+    /// Returns the referenced JavaScript object, or `None` if the reference is weak and
+    /// the object has been garbage collected.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if it is called from a different JavaScript thread than the
+    /// one in which the reference was created.
+    pub fn get<'a, C: Context<'a>>(&self, cx: &mut C) -> Option<Handle<'a, T>> {
+        let env = cx.env();
+        let internal = self.as_napi_ref(cx).0.cast();
+        let local = unsafe { reference::get(env.to_raw(), internal) };
+
+        if local.is_null() {
+            return None;
+        }
+
+        Some(Handle::new_internal(unsafe { T::from_local(env, local) }))
+    }
+
+    /// Deletes the reference immediately, instead of from a global queue when dropped.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if it is called from a different JavaScript thread than the
+    /// one in which the reference was created.
+    pub fn delete<'a, C: Context<'a>>(mut self, cx: &mut C) {
+        let env = cx.env().to_raw();
+
+        // Check the instance before taking the reference
+        self.as_napi_ref(cx);
+
+        if let Some(internal) = self.internal.take() {
+            unsafe { internal.delete(env) };
+        }
+    }
+
+    fn as_napi_ref<'a, C: Context<'a>>(&self, cx: &mut C) -> &NapiRef {
+        if self.instance_id != instance_id(cx) {
+            panic!("Attempted to use a `neon::handle::Reference` from the wrong module");
+        }
+
+        self.internal
+            .as_ref()
+            // `unwrap` will not `panic` because `internal` is `Some` until consumed
+            .unwrap()
+    }
+}
+
+// Allows putting `Reference<T>` directly in a container that implements `Finalize`
+impl<T: Object> Finalize for Reference<T> {
+    fn finalize<'a, C: Context<'a>>(self, cx: &mut C) {
+        self.delete(cx);
+    }
+}
+
+impl<T> Drop for Reference<T> {
+    fn drop(&mut self) {
+        if let Some(internal) = self.internal.take() {
+            let _ = self.drop_queue.call(DropData::Delete(internal), None);
+        }
+    }
+}
+
+/// A weak reference to a JavaScript object that does not prevent it from being
+/// garbage collected.
+///
+/// `Weak<T>` is useful for caches and back-references that should not keep objects
+/// alive. It is a [`Reference<T>`] with a count that is always zero.
+///
+/// ```
+/// # use neon::prelude::*;
+/// use neon::handle::Weak;
+///
+/// struct Cache(Weak<JsObject>);
+///
+/// impl Finalize for Cache {
+///     fn finalize<'a, C: Context<'a>>(self, cx: &mut C) {
+///         self.0.finalize(cx);
+///     }
+/// }
+///
+/// fn cache(mut cx: FunctionContext) -> JsResult<JsBox<Cache>> {
+///     let obj = cx.argument::<JsObject>(0)?;
+///
+///     Ok(cx.boxed(Cache(Weak::new(&mut cx, obj))))
+/// }
+///
+/// fn cached(mut cx: FunctionContext) -> JsResult<JsValue> {
+///     let cache = cx.argument::<JsBox<Cache>>(0)?;
+///
+///     match cache.0.get(&mut cx) {
+///         Some(obj) => Ok(obj.upcast()),
+///         None => Ok(cx.undefined().upcast()),
+///     }
+/// }
+/// ```
+pub struct Weak<T>(Reference<T>);
+
+impl<T> std::fmt::Debug for Weak<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Weak<{}>", std::any::type_name::<T>())
+    }
+}
+
+impl<T: Object> Weak<T> {
+    /// Creates a weak reference to a JavaScript object.
+    pub fn new<'a, C: Context<'a>>(cx: &mut C, value: Handle<T>) -> Self {
+        Self(Reference::new(cx, value, 0))
+    }
+
+    /// Returns the referenced JavaScript object, or `None` if it has been garbage
+    /// collected.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if it is called from a different JavaScript thread than the
+    /// one in which the reference was created.
+    pub fn get<'a, C: Context<'a>>(&self, cx: &mut C) -> Option<Handle<'a, T>> {
+        self.0.get(cx)
+    }
+
+    /// Deletes the reference immediately, instead of from a global queue when dropped.
+    pub fn delete<'a, C: Context<'a>>(self, cx: &mut C) {
+        self.0.delete(cx);
+    }
+}
+
+impl<T: Object> Finalize for Weak<T> {
+    fn finalize<'a, C: Context<'a>>(self, cx: &mut C) {
+        self.delete(cx);
+    }
+}
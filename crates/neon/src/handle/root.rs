@@ -1,20 +1,17 @@
-use std::{ffi::c_void, marker::PhantomData};
+use std::{ffi::c_void, marker::PhantomData, sync::Arc};
 
 use crate::{
     context::Context,
-    handle::Handle,
+    handle::{DowncastResult, Handle},
     object::Object,
     sys::{raw, reference},
-    types::boxed::Finalize,
+    types::{boxed::Finalize, Value},
 };
 
 #[cfg(feature = "napi-6")]
-use {
-    crate::{
-        lifecycle::{DropData, InstanceData, InstanceId},
-        sys::tsfn::ThreadsafeFunction,
-    },
-    std::sync::Arc,
+use crate::{
+    lifecycle::{DropData, InstanceData, InstanceId},
+    sys::tsfn::ThreadsafeFunction,
 };
 
 #[cfg(not(feature = "napi-6"))]
@@ -25,7 +22,7 @@ type InstanceId = ThreadId;
 
 #[repr(transparent)]
 #[derive(Clone)]
-pub(crate) struct NapiRef(*mut c_void);
+pub(crate) struct NapiRef(pub(crate) *mut c_void);
 
 impl NapiRef {
     /// # Safety
@@ -33,6 +30,13 @@ impl NapiRef {
     pub(crate) unsafe fn unref(self, env: raw::Env) {
         reference::unreference(env, self.0.cast());
     }
+
+    /// # Safety
+    /// Must only be used from the same module context that created the reference
+    #[cfg(feature = "napi-6")]
+    pub(crate) unsafe fn delete(self, env: raw::Env) {
+        reference::delete(env, self.0.cast());
+    }
 }
 
 // # Safety
@@ -49,10 +53,19 @@ unsafe impl Sync for NapiRef {}
 ///
 /// A `Root<T>` may be sent across threads, but the referenced object may
 /// only be accessed on the JavaScript thread that created it.
+///
+/// # Cloning
+///
+/// [`Root::share`] may be called from any thread. Shared handles use a single N-API
+/// reference with an atomic reference count; the reference is only released on the
+/// JavaScript thread when the last handle is dropped.
+///
+/// [`Root::clone`] instead increments the count of the N-API reference directly and
+/// must be called on the JavaScript thread.
 pub struct Root<T> {
     // `Option` is used to skip `Drop` when `Root::drop` or `Root::into_inner` is used.
     // It will *always* be `Some` when a user is interacting with `Root`.
-    internal: Option<NapiRef>,
+    internal: Option<Arc<NapiRef>>,
     instance_id: InstanceId,
     #[cfg(feature = "napi-6")]
     drop_queue: Arc<ThreadsafeFunction<DropData>>,
@@ -73,7 +86,7 @@ unsafe impl<T> Send for Root<T> {}
 unsafe impl<T> Sync for Root<T> {}
 
 #[cfg(feature = "napi-6")]
-fn instance_id<'a, C: Context<'a>>(cx: &mut C) -> InstanceId {
+pub(crate) fn instance_id<'a, C: Context<'a>>(cx: &mut C) -> InstanceId {
     InstanceData::id(cx)
 }
 
@@ -94,10 +107,10 @@ impl<T: Object> Root<T> {
     /// * N-API >= 6, Neon will drop from a global queue at a runtime cost
     pub fn new<'a, C: Context<'a>>(cx: &mut C, value: &T) -> Self {
         let env = cx.env().to_raw();
-        let internal = unsafe { reference::new(env, value.to_local()) };
+        let internal = unsafe { reference::new(env, value.to_local(), 1) };
 
         Self {
-            internal: Some(NapiRef(internal as *mut _)),
+            internal: Some(Arc::new(NapiRef(internal as *mut _))),
             instance_id: instance_id(cx),
             #[cfg(feature = "napi-6")]
             drop_queue: InstanceData::drop_queue(cx),
@@ -105,8 +118,9 @@ impl<T: Object> Root<T> {
         }
     }
 
-    /// Clone a reference to the contained JavaScript object. This method can
-    /// be considered identical to the following:
+    /// Clone a reference to the contained JavaScript object. Unlike [`Root::share`],
+    /// which shares an atomic count, the clone shares the same N-API reference and
+    /// increments its count. This method can be considered identical to the following:
     /// ```
     /// # use neon::prelude::*;
     /// # fn my_neon_function(mut cx: FunctionContext) -> JsResult<JsUndefined> {
@@ -126,7 +140,7 @@ impl<T: Object> Root<T> {
         };
 
         Self {
-            internal: self.internal.clone(),
+            internal: Some(Arc::new(NapiRef(internal as *mut _))),
             instance_id: instance_id(cx),
             #[cfg(feature = "napi-6")]
             drop_queue: Arc::clone(&self.drop_queue),
@@ -139,27 +153,59 @@ impl<T: Object> Root<T> {
     pub fn drop<'a, C: Context<'a>>(self, cx: &mut C) {
         let env = cx.env().to_raw();
 
-        unsafe {
-            self.into_napi_ref(cx).unref(env);
+        if let Some(internal) = self.into_napi_ref(cx) {
+            unsafe {
+                internal.unref(env);
+            }
         }
     }
 
     /// Return the referenced JavaScript object and allow it to be garbage collected.
     ///
+    /// The object is only released once all handles created with [`Root::share`] have
+    /// been dropped.
+    ///
     /// # Panics
     ///
     /// This method panics if it is called from a different JavaScript thread than the
     /// one in which the handle was created.
     pub fn into_inner<'a, C: Context<'a>>(self, cx: &mut C) -> Handle<'a, T> {
+        let handle = self.to_inner(cx);
+
+        self.drop(cx);
+        handle
+    }
+
+    /// Return the referenced JavaScript object and release the reference if this is
+    /// the only handle to it created with [`Root::share`]. Otherwise, the `Root` is
+    /// returned unchanged.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if it is called from a different JavaScript thread than the
+    /// one in which the handle was created.
+    pub fn try_unwrap<'a, C: Context<'a>>(mut self, cx: &mut C) -> Result<Handle<'a, T>, Self> {
         let env = cx.env();
-        let internal = self.into_napi_ref(cx);
-        let local = unsafe { reference::get(env.to_raw(), internal.0.cast()) };
+        let internal = Arc::clone(self.as_napi_ref(cx));
 
-        unsafe {
-            internal.unref(env.to_raw());
-        }
+        // Release our own count so that uniqueness can be checked atomically
+        self.internal = None;
 
-        Handle::new_internal(unsafe { T::from_local(env, local) })
+        match Arc::try_unwrap(internal) {
+            Ok(internal) => {
+                let local = unsafe { reference::get(env.to_raw(), internal.0.cast()) };
+
+                unsafe {
+                    internal.unref(env.to_raw());
+                }
+
+                Ok(Handle::new_internal(unsafe { T::from_local(env, local) }))
+            }
+            Err(internal) => {
+                self.internal = Some(internal);
+                Err(self)
+            }
+        }
     }
 
     /// Access the inner JavaScript object without consuming the `Root`
@@ -177,7 +223,45 @@ impl<T: Object> Root<T> {
         Handle::new_internal(unsafe { T::from_local(env, local) })
     }
 
-    fn as_napi_ref<'a, C: Context<'a>>(&self, cx: &mut C) -> &NapiRef {
+    /// Attempts to downcast the inner JavaScript object to another type without
+    /// consuming the `Root`. Like [`Handle::downcast`], a failure does not throw.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if it is called from a different JavaScript thread than the
+    /// one in which the handle was created.
+    pub fn downcast<'a, U: Value, C: Context<'a>>(&self, cx: &mut C) -> DowncastResult<'a, T, U> {
+        self.to_inner(cx).downcast(cx)
+    }
+
+    /// Tests whether this `Root` and `other` reference the same JavaScript object, like
+    /// the JavaScript `===` operator.
+    ///
+    /// This is useful for finding a `Root` in a collection, e.g., removing a listener.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// struct Listeners(Vec<Root<JsFunction>>);
+    ///
+    /// impl Listeners {
+    ///     fn remove(&mut self, cx: &mut FunctionContext, listener: Root<JsFunction>) {
+    ///         self.0.retain(|root| !root.equals(cx, &listener));
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This method panics if it is called from a different JavaScript thread than the
+    /// one in which either handle was created.
+    pub fn equals<'a, U: Object, C: Context<'a>>(&self, cx: &mut C, other: &Root<U>) -> bool {
+        let this = self.to_inner(cx);
+        let other = other.to_inner(cx);
+
+        this.strict_equals(cx, other)
+    }
+
+    fn as_napi_ref<'a, C: Context<'a>>(&self, cx: &mut C) -> &Arc<NapiRef> {
         if self.instance_id != instance_id(cx) {
             panic!("Attempted to dereference a `neon::handle::Root` from the wrong module ");
         }
@@ -189,11 +273,27 @@ impl<T: Object> Root<T> {
             .unwrap()
     }
 
-    fn into_napi_ref<'a, C: Context<'a>>(mut self, cx: &mut C) -> NapiRef {
-        let reference = self.as_napi_ref(cx).clone();
-        // This uses `as_napi_ref` instead of `Option::take` for the instance id safety check
-        self.internal = None;
-        reference
+    // Returns the `NapiRef` if this was the last clone and it must be released
+    fn into_napi_ref<'a, C: Context<'a>>(mut self, cx: &mut C) -> Option<NapiRef> {
+        // This uses `as_napi_ref` before `Option::take` for the instance id safety check
+        self.as_napi_ref(cx);
+
+        self.internal.take().and_then(Arc::into_inner)
+    }
+}
+
+impl<T> Root<T> {
+    /// Create another handle to the same N-API reference by incrementing an atomic
+    /// reference count. Unlike [`Root::clone`], this does not need a context and may
+    /// be called from any thread.
+    pub fn share(&self) -> Self {
+        Self {
+            internal: self.internal.clone(),
+            instance_id: self.instance_id,
+            #[cfg(feature = "napi-6")]
+            drop_queue: Arc::clone(&self.drop_queue),
+            _phantom: PhantomData,
+        }
     }
 }
 
@@ -208,8 +308,9 @@ impl<T: Object> Finalize for Root<T> {
 impl<T> Drop for Root<T> {
     #[cfg(not(feature = "napi-6"))]
     fn drop(&mut self) {
-        // If `None`, the `NapiRef` has already been manually dropped
-        if self.internal.is_none() {
+        // If `None`, the `NapiRef` has already been manually dropped. Otherwise,
+        // only the last clone needs to release the reference.
+        if self.internal.take().and_then(Arc::into_inner).is_none() {
             return;
         }
 
@@ -228,8 +329,9 @@ impl<T> Drop for Root<T> {
 
     #[cfg(feature = "napi-6")]
     fn drop(&mut self) {
-        // If `None`, the `NapiRef` has already been manually dropped
-        if let Some(internal) = self.internal.take() {
+        // If `None`, the `NapiRef` has already been manually dropped. Otherwise,
+        // only the last clone needs to release the reference.
+        if let Some(internal) = self.internal.take().and_then(Arc::into_inner) {
             let _ = self.drop_queue.call(DropData::Ref(internal), None);
         }
     }
@@ -78,6 +78,9 @@
 //! [supported]: https://github.com/neon-bindings/neon#platform-support
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
+#[cfg(feature = "napi-8")]
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-8")))]
+pub mod class;
 pub mod context;
 pub mod event;
 pub mod handle;
@@ -87,6 +90,9 @@ pub mod object;
 pub mod prelude;
 pub mod reflect;
 pub mod result;
+#[cfg(all(feature = "serde", feature = "napi-6"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "serde", feature = "napi-6"))))]
+pub mod serde;
 #[cfg(not(feature = "sys"))]
 mod sys;
 #[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
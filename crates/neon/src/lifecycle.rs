@@ -215,8 +215,10 @@ impl<'cx, 'a, C: Context<'cx>> Drop for TryInitTransaction<'cx, 'a, C> {
 
 /// Wrapper for raw Node-API values to be dropped on the main thread
 pub(crate) enum DropData {
-    Deferred(NodeApiDeferred),
+    Deferred(NodeApiDeferred, Option<String>),
     Ref(NapiRef),
+    // Deleted regardless of the reference count
+    Delete(NapiRef),
 }
 
 impl DropData {
@@ -225,8 +227,9 @@ impl DropData {
         if let Some(env) = env {
             unsafe {
                 match data {
-                    DropData::Deferred(data) => data.leaked(env),
+                    DropData::Deferred(data, message) => data.leaked(env, message),
                     DropData::Ref(data) => data.unref(env),
+                    DropData::Delete(data) => data.delete(env),
                 }
             }
         }
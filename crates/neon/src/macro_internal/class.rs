@@ -0,0 +1,42 @@
+use crate::{
+    class::{self, Class},
+    context::{Context, FunctionContext},
+    result::JsResult,
+    types::{JsValue, Value},
+};
+
+// Method registered on the prototype of a class
+pub struct ClassMethod {
+    pub name: &'static str,
+    pub method: for<'cx> fn(FunctionContext<'cx>) -> JsResult<'cx, JsValue>,
+}
+
+// Calls a `&self` method
+pub fn class_method<'cx, T, V, F>(mut cx: FunctionContext<'cx>, f: F) -> JsResult<'cx, JsValue>
+where
+    T: Class,
+    V: Value,
+    F: FnOnce(&T, FunctionContext<'cx>) -> JsResult<'cx, V>,
+{
+    let instance = class::instance::<T>(&mut cx)?;
+    let Ok(this) = instance.try_borrow() else {
+        return cx.throw_error(format!("{} is already mutably borrowed", T::NAME));
+    };
+
+    f(&this, cx).map(|v| v.upcast())
+}
+
+// Calls a `&mut self` method
+pub fn class_method_mut<'cx, T, V, F>(mut cx: FunctionContext<'cx>, f: F) -> JsResult<'cx, JsValue>
+where
+    T: Class,
+    V: Value,
+    F: FnOnce(&mut T, FunctionContext<'cx>) -> JsResult<'cx, V>,
+{
+    let instance = class::instance::<T>(&mut cx)?;
+    let Ok(mut this) = instance.try_borrow_mut() else {
+        return cx.throw_error(format!("{} is already borrowed", T::NAME));
+    };
+
+    f(&mut this, cx).map(|v| v.upcast())
+}
@@ -14,9 +14,15 @@ use crate::{
 #[cfg(feature = "serde")]
 use crate::types::extract::Json;
 
+#[cfg(feature = "napi-8")]
+pub use self::class::*;
+
 #[cfg(all(feature = "napi-6", feature = "futures"))]
 pub use self::futures::*;
 
+#[cfg(feature = "napi-8")]
+mod class;
+
 #[cfg(all(feature = "napi-6", feature = "futures"))]
 mod futures;
 
@@ -241,8 +241,8 @@ pub use neon_macros::main;
 /// to a function is a _context_ argument.
 ///
 /// * In a function executed on the JavaScript main thread, it looks for `&mut Cx`
-///     or `&mut FunctionContext` to determine if the [`Context`](crate::context::Context)
-///     should be passed.
+///   or `&mut FunctionContext` to determine if the [`Context`](crate::context::Context)
+///   should be passed.
 /// * In a function executed on another thread, it looks for [`Channel`](crate::event::Channel).
 ///
 /// If the type has been renamed when importing, the `context` attribute can be
@@ -296,3 +296,58 @@ pub use neon_macros::main;
 /// }
 /// ```
 pub use neon_macros::export;
+
+/// Expose a Rust type to JavaScript as a class
+///
+/// The attribute is applied to an inherent `impl` block. Exactly one function must be
+/// marked `#[constructor]`; it is called by `new` and returns the Rust data owned by the
+/// instance. Functions marked `#[method]` are added to the class prototype and take
+/// `&self` or `&mut self` along with a [`FunctionContext`](crate::context::FunctionContext).
+/// Other items in the block are left unchanged.
+///
+/// ```
+/// # use neon::prelude::*;
+/// struct Person {
+///     name: String,
+/// }
+///
+/// #[neon::class]
+/// impl Person {
+///     #[constructor]
+///     fn new(mut cx: FunctionContext) -> NeonResult<Self> {
+///         let name = cx.argument::<JsString>(0)?.value(&mut cx);
+///
+///         Ok(Self { name })
+///     }
+///
+///     #[method(name = "setName")]
+///     fn set_name<'cx>(&mut self, mut cx: FunctionContext<'cx>) -> JsResult<'cx, JsUndefined> {
+///         self.name = cx.argument::<JsString>(0)?.value(&mut cx);
+///
+///         Ok(cx.undefined())
+///     }
+///
+///     #[method]
+///     fn greet<'cx>(&self, mut cx: FunctionContext<'cx>) -> JsResult<'cx, JsString> {
+///         Ok(cx.string(format!("Hello, {}!", self.name)))
+///     }
+/// }
+///
+/// #[neon::main]
+/// fn main(mut cx: ModuleContext) -> NeonResult<()> {
+///     cx.export_class::<Person>()
+/// }
+/// ```
+///
+/// Methods return handles with the lifetime of the context, so the lifetime must be
+/// named explicitly instead of being elided to the lifetime of `self`.
+///
+/// The class name defaults to the name of the type and may be changed with the `name`
+/// attribute, e.g. `#[neon::class(name = "Person")]`. See the [`class`](mod@crate::class)
+/// module for details on how instances are borrowed.
+///
+/// Classes require Node-API 8, which is used to check that `this` is an instance
+/// created by this module before its Rust data is accessed.
+#[cfg(feature = "napi-8")]
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-8")))]
+pub use neon_macros::class;
@@ -8,8 +8,9 @@
 //! ## Property Keys
 //!
 //! Object properties are accessed by a _property key_, which in JavaScript
-//! can be a string or [symbol][symbol]. (Neon does not yet have support for
-//! symbols.) For convenience, the [`PropertyKey`] trait allows
+//! can be a string or [symbol][symbol]. Symbols are represented by
+//! [`JsSymbol`](crate::types::JsSymbol) and a `Handle<JsSymbol>` can be used
+//! as a key directly. For convenience, the [`PropertyKey`] trait allows
 //! Neon programs to use various Rust string types, as well as numeric types,
 //! as keys when accessing object properties, converting the keys to strings
 //! as necessary:
@@ -36,23 +37,26 @@ use smallvec::smallvec;
 use crate::{
     context::{internal::ContextInternal, Context, Cx},
     handle::{Handle, Root},
-    result::{NeonResult, Throw},
+    result::{JsResult, NeonResult, Throw},
     sys::{self, raw},
     types::{
         build,
         extract::{TryFromJs, TryIntoJs},
-        function::{BindOptions, CallOptions},
+        function::{BindOptions, CallOptions, TryIntoArguments},
         private::ValueInternal,
         utf8::Utf8,
-        JsFunction, JsUndefined, JsValue, Value,
+        JsFunction, JsObject, JsUndefined, JsValue, Value,
     },
 };
 
 #[cfg(feature = "napi-6")]
-use crate::{result::JsResult, types::JsArray};
+use crate::types::JsArray;
 
 /// A property key in a JavaScript object.
 pub trait PropertyKey: Copy {
+    /// # Safety
+    ///
+    /// `obj` must be a valid object in the current scope of `cx`.
     unsafe fn get_from<'c, C: Context<'c>>(
         self,
         cx: &mut C,
@@ -60,6 +64,9 @@ pub trait PropertyKey: Copy {
         obj: raw::Local,
     ) -> bool;
 
+    /// # Safety
+    ///
+    /// `obj` and `val` must be valid values in the current scope of `cx`.
     unsafe fn set_from<'c, C: Context<'c>>(
         self,
         cx: &mut C,
@@ -115,7 +122,7 @@ impl<'a, K: Value> PropertyKey for Handle<'a, K> {
     }
 }
 
-impl<'a> PropertyKey for &'a str {
+impl PropertyKey for &str {
     unsafe fn get_from<'c, C: Context<'c>>(
         self,
         cx: &mut C,
@@ -142,6 +149,27 @@ impl<'a> PropertyKey for &'a str {
     }
 }
 
+impl PropertyKey for &String {
+    unsafe fn get_from<'c, C: Context<'c>>(
+        self,
+        cx: &mut C,
+        out: &mut raw::Local,
+        obj: raw::Local,
+    ) -> bool {
+        self.as_str().get_from(cx, out, obj)
+    }
+
+    unsafe fn set_from<'c, C: Context<'c>>(
+        self,
+        cx: &mut C,
+        out: &mut bool,
+        obj: raw::Local,
+        val: raw::Local,
+    ) -> bool {
+        self.as_str().set_from(cx, out, obj, val)
+    }
+}
+
 /// A builder for accessing an object property.
 ///
 /// The builder methods make it convenient to get and set properties
@@ -254,6 +282,152 @@ where
     }
 }
 
+#[cfg(feature = "napi-6")]
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
+#[derive(Clone, Copy, Debug, Default)]
+/// Options for selecting the property names returned by [`Object::property_names`].
+///
+/// By default, only own enumerable string keys are included, matching
+/// [`Object.keys`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/keys).
+/// Numeric keys are always converted to strings.
+///
+/// ```
+/// # use neon::prelude::*;
+/// use neon::object::PropertyNamesOptions;
+///
+/// fn all_names(mut cx: FunctionContext) -> JsResult<JsArray> {
+///     let obj = cx.argument::<JsObject>(0)?;
+///     let options = PropertyNamesOptions::new()
+///         .include_prototypes(true)
+///         .include_non_enumerable(true);
+///
+///     obj.property_names(&mut cx, options)
+/// }
+/// ```
+pub struct PropertyNamesOptions {
+    include_prototypes: bool,
+    include_non_enumerable: bool,
+    include_symbols: bool,
+}
+
+#[cfg(feature = "napi-6")]
+impl PropertyNamesOptions {
+    /// Creates options that select own enumerable string keys
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Include properties inherited from the prototype chain
+    pub fn include_prototypes(mut self, include: bool) -> Self {
+        self.include_prototypes = include;
+        self
+    }
+
+    /// Include properties that are not enumerable
+    pub fn include_non_enumerable(mut self, include: bool) -> Self {
+        self.include_non_enumerable = include;
+        self
+    }
+
+    /// Include symbol keys
+    pub fn include_symbols(mut self, include: bool) -> Self {
+        self.include_symbols = include;
+        self
+    }
+}
+
+/// Describes a property defined with [`Object::define_property`].
+///
+/// Like the descriptors accepted by
+/// [`Object.defineProperty`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/defineProperty),
+/// a descriptor either has a `value` or a getter and setter pair, and all of the
+/// attribute flags default to `false`. The `writable` flag only applies to descriptors
+/// with a `value`.
+///
+/// Getters and setters are ordinary functions, so they can be created from Rust closures
+/// with [`JsFunction::new`].
+///
+/// ```
+/// # use neon::prelude::*;
+/// use neon::object::PropertyDescriptor;
+///
+/// fn make_point(mut cx: FunctionContext) -> JsResult<JsObject> {
+///     let point = cx.empty_object();
+///     let x = cx.number(3);
+///     let norm = JsFunction::new(&mut cx, |mut cx| {
+///         let this = cx.this::<JsObject>()?;
+///         let x: f64 = this.prop(&mut cx, "x").get()?;
+///
+///         Ok(cx.number(x.abs()))
+///     })?;
+///
+///     // A read-only data property and a computed property
+///     let x = PropertyDescriptor::new().value(x).enumerable(true);
+///     let norm = PropertyDescriptor::new().getter(norm);
+///
+///     point.define_property(&mut cx, "x", x)?;
+///     point.define_property(&mut cx, "norm", norm)?;
+///
+///     Ok(point)
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PropertyDescriptor<'cx> {
+    value: Option<Handle<'cx, JsValue>>,
+    getter: Option<Handle<'cx, JsFunction>>,
+    setter: Option<Handle<'cx, JsFunction>>,
+    writable: bool,
+    enumerable: bool,
+    configurable: bool,
+}
+
+impl<'cx> PropertyDescriptor<'cx> {
+    /// Creates a descriptor with no value and all attributes `false`
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The value of the property
+    pub fn value<V: Value>(mut self, value: Handle<'cx, V>) -> Self {
+        self.value = Some(value.upcast());
+        self
+    }
+
+    /// A function called with the object as `this` to get the value of the property
+    pub fn getter(mut self, getter: Handle<'cx, JsFunction>) -> Self {
+        self.getter = Some(getter);
+        self
+    }
+
+    /// A function called with the object as `this` and the new value to set the property
+    pub fn setter(mut self, setter: Handle<'cx, JsFunction>) -> Self {
+        self.setter = Some(setter);
+        self
+    }
+
+    /// Allow the value to be changed by assignment
+    pub fn writable(mut self, writable: bool) -> Self {
+        self.writable = writable;
+        self
+    }
+
+    /// Include the property when enumerating the object's properties
+    pub fn enumerable(mut self, enumerable: bool) -> Self {
+        self.enumerable = enumerable;
+        self
+    }
+
+    /// Allow the property to be deleted or redefined
+    pub fn configurable(mut self, configurable: bool) -> Self {
+        self.configurable = configurable;
+        self
+    }
+
+    fn is_accessor(&self) -> bool {
+        self.getter.is_some() || self.setter.is_some()
+    }
+}
+
 /// The trait of all object types.
 pub trait Object: Value {
     /// Create a [`PropOptions`] for accessing a property.
@@ -295,6 +469,45 @@ pub trait Object: Value {
         })
     }
 
+    /// Calls the method `name` of the object with `args`, binding `this` to the object,
+    /// and converts the result with [`TryFromJs`].
+    ///
+    /// Throws a `TypeError` if the property is not a function, e.g.,
+    /// `"send is not a function"`.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn greet(mut cx: FunctionContext) -> JsResult<JsString> {
+    ///     let greeter = cx.argument::<JsObject>(0)?;
+    ///
+    ///     greeter.call_method(&mut cx, "greet", ("World",))
+    /// }
+    /// ```
+    ///
+    /// **See also:** [`Object::method`]
+    fn call_method<'cx, A, R>(&self, cx: &mut Cx<'cx>, name: &str, args: A) -> NeonResult<R>
+    where
+        A: TryIntoArguments<'cx>,
+        R: TryFromJs<'cx>,
+    {
+        let callee: Handle<JsValue> = self.prop(cx, name).get()?;
+
+        if !callee.is_a::<JsFunction, _>(cx) {
+            return cx.throw_type_error(format!("{name} is not a function"));
+        }
+
+        let this = Some(self.as_value(cx));
+
+        BindOptions {
+            cx,
+            callee,
+            this,
+            args: smallvec![],
+        }
+        .args(args)?
+        .call()
+    }
+
     #[deprecated(since = "TBD", note = "use `Object::prop()` instead")]
     fn get_opt<'a, V: Value, C: Context<'a>, K: PropertyKey>(
         &self,
@@ -340,6 +553,253 @@ pub trait Object: Value {
         })
     }
 
+    #[cfg(feature = "napi-6")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
+    /// Gets the names of the object's properties as an array, selected by `options`.
+    ///
+    /// **See also:** [`Object::keys`]
+    fn property_names<'cx>(
+        &self,
+        cx: &mut Cx<'cx>,
+        options: PropertyNamesOptions,
+    ) -> JsResult<'cx, JsArray> {
+        let env = cx.env();
+        let mode = if options.include_prototypes {
+            sys::bindings::KeyCollectionMode::IncludePrototypes
+        } else {
+            sys::bindings::KeyCollectionMode::OwnOnly
+        };
+        let mut filter = sys::bindings::KeyFilter::ALL_PROPERTIES;
+
+        if !options.include_non_enumerable {
+            filter |= sys::bindings::KeyFilter::ENUMERABLE;
+        }
+
+        if !options.include_symbols {
+            filter |= sys::bindings::KeyFilter::SKIP_SYMBOLS;
+        }
+
+        build(env, |out| unsafe {
+            sys::object::get_property_names(out, env.to_raw(), self.to_local(), mode, filter)
+        })
+    }
+
+    #[cfg(feature = "napi-6")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
+    /// Gets the object's own enumerable string keys, equivalent to the JavaScript
+    /// expression `Object.keys(this)`.
+    fn keys(&self, cx: &mut Cx) -> NeonResult<Vec<String>> {
+        let names = self.property_names(cx, PropertyNamesOptions::new())?;
+
+        names.to_vec_of(cx)
+    }
+
+    #[cfg(feature = "napi-6")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
+    /// Gets the object's own enumerable string keys paired with their values, equivalent
+    /// to the JavaScript expression `Object.entries(this)`.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// use std::collections::HashMap;
+    ///
+    /// fn to_map(mut cx: FunctionContext) -> NeonResult<HashMap<String, String>> {
+    ///     let obj = cx.argument::<JsObject>(0)?;
+    ///
+    ///     obj.entries(&mut cx)?
+    ///         .into_iter()
+    ///         .map(|(k, v)| Ok((k, v.to_string(&mut cx)?.value(&mut cx))))
+    ///         .collect()
+    /// }
+    /// ```
+    fn entries<'cx>(&self, cx: &mut Cx<'cx>) -> NeonResult<Vec<(String, Handle<'cx, JsValue>)>> {
+        self.keys(cx)?
+            .into_iter()
+            .map(|key| {
+                let v = self.get_value(cx, key.as_str())?;
+
+                Ok((key, v))
+            })
+            .collect()
+    }
+
+    /// Defines or modifies a property of the object with the global `Object.defineProperty`.
+    ///
+    /// Throws a `TypeError` if the property cannot be defined, for example if it exists
+    /// and is not configurable, or if the descriptor has both a value and accessors.
+    fn define_property<'cx, K>(
+        &self,
+        cx: &mut Cx<'cx>,
+        key: K,
+        descriptor: PropertyDescriptor<'cx>,
+    ) -> NeonResult<()>
+    where
+        K: PropertyKey,
+    {
+        let object: Handle<JsFunction> = cx.global("Object")?;
+        let this = self.as_value(cx);
+        let desc = cx.empty_object();
+
+        if let Some(value) = descriptor.value {
+            desc.prop(cx, "value").set(value)?;
+        }
+
+        if let Some(getter) = descriptor.getter {
+            desc.prop(cx, "get").set(getter)?;
+        }
+
+        if let Some(setter) = descriptor.setter {
+            desc.prop(cx, "set").set(setter)?;
+        }
+
+        // Accessor descriptors may not have a `writable` attribute
+        if !descriptor.is_accessor() {
+            desc.prop(cx, "writable").set(descriptor.writable)?;
+        }
+
+        desc.prop(cx, "enumerable").set(descriptor.enumerable)?;
+        desc.prop(cx, "configurable").set(descriptor.configurable)?;
+
+        // `PropertyKey` can only be used to get and set properties, so the descriptor is
+        // passed to `Object.defineProperties` as a property of another object. It has a
+        // `null` prototype so a `__proto__` key is not handled by the inherited setter.
+        let null = cx.null();
+        let descriptors: Handle<JsObject> = object.method(cx, "create")?.arg(null)?.call()?;
+
+        descriptors.prop(cx, key).set(desc)?;
+
+        object
+            .method(cx, "defineProperties")?
+            .arg(this)?
+            .arg(descriptors)?
+            .exec()
+    }
+
+    /// Checks if the object has an own property named `key`, ignoring properties
+    /// inherited from the prototype chain, like `Object.hasOwn` in JavaScript.
+    ///
+    /// Throws a `TypeError` if `key` is not a string or symbol. Array indices must be
+    /// given as strings, e.g. `"0"`.
+    fn has_own<'cx, K: TryIntoJs<'cx>>(&self, cx: &mut Cx<'cx>, key: K) -> NeonResult<bool> {
+        let key = key.try_into_js(cx)?;
+        let mut result = false;
+
+        unsafe {
+            match sys::object::has_own(
+                &mut result,
+                cx.env().to_raw(),
+                self.to_local(),
+                key.to_local(),
+            ) {
+                sys::Status::Ok => Ok(result),
+                sys::Status::PendingException => Err(Throw::new()),
+                _ => cx.throw_type_error("property key must be a string or symbol"),
+            }
+        }
+    }
+
+    /// Gets an own property of the object, returning `None` if `key` is not an own
+    /// property. Unlike [`Object::prop`], properties inherited from the prototype chain,
+    /// such as `toString`, are never returned.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn get_name(mut cx: FunctionContext) -> JsResult<JsValue> {
+    ///     let options = cx.argument::<JsObject>(0)?;
+    ///
+    ///     match options.get_own(&mut cx, "name")? {
+    ///         Some(name) => Ok(name),
+    ///         None => Ok(cx.string("anonymous").upcast()),
+    ///     }
+    /// }
+    /// ```
+    fn get_own<'cx, K: TryIntoJs<'cx>>(
+        &self,
+        cx: &mut Cx<'cx>,
+        key: K,
+    ) -> NeonResult<Option<Handle<'cx, JsValue>>> {
+        let key = key.try_into_js(cx)?.upcast::<JsValue>();
+
+        if !self.has_own(cx, key)? {
+            return Ok(None);
+        }
+
+        self.prop(cx, key).get().map(Some)
+    }
+
+    /// Returns the prototype of the object, like `Object.getPrototypeOf` in JavaScript.
+    /// The prototype is either an object or `null`.
+    fn get_prototype<'cx>(&self, cx: &mut Cx<'cx>) -> JsResult<'cx, JsValue> {
+        build(cx.env(), |out| unsafe {
+            sys::object::get_prototype(out, cx.env().to_raw(), self.to_local())
+        })
+    }
+
+    /// Sets the prototype of the object to `proto`, an object or `null`, with the global
+    /// `Object.setPrototypeOf`. Throws a `TypeError` if the object is not extensible.
+    ///
+    /// Changing the prototype of an existing object is slow in most JavaScript engines
+    /// and may slow down later accesses to the object. Prefer creating objects with the
+    /// intended prototype where possible.
+    fn set_prototype<'cx, V: Value>(
+        &self,
+        cx: &mut Cx<'cx>,
+        proto: Handle<'cx, V>,
+    ) -> NeonResult<()> {
+        let object: Handle<JsFunction> = cx.global("Object")?;
+        let this = self.as_value(cx);
+
+        object
+            .method(cx, "setPrototypeOf")?
+            .arg(this)?
+            .arg(proto)?
+            .exec()
+    }
+
+    /// Copies the own enumerable properties of `source` to the object with the global
+    /// `Object.assign`, invoking getters on `source` and setters on the object.
+    ///
+    /// This is a shallow copy: property values that are objects are shared rather than
+    /// cloned.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn with_defaults(mut cx: FunctionContext) -> JsResult<JsObject> {
+    ///     let options = cx.argument::<JsObject>(0)?;
+    ///     let result = cx.empty_object();
+    ///
+    ///     result.prop(&mut cx, "verbose").set(false)?;
+    ///     result.assign(&mut cx, options)?;
+    ///
+    ///     Ok(result)
+    /// }
+    /// ```
+    ///
+    /// **See also:** [`Object::assign_all`]
+    fn assign<'cx, V: Object>(&self, cx: &mut Cx<'cx>, source: Handle<'cx, V>) -> NeonResult<()> {
+        self.assign_all(cx, &[source.upcast()])
+    }
+
+    /// Copies the own enumerable properties of each of `sources` to the object in order,
+    /// like [`Object::assign`]. Later sources overwrite properties of earlier ones.
+    fn assign_all<'cx>(
+        &self,
+        cx: &mut Cx<'cx>,
+        sources: &[Handle<'cx, JsObject>],
+    ) -> NeonResult<()> {
+        let object: Handle<JsFunction> = cx.global("Object")?;
+        let this = self.as_value(cx);
+        let mut assign = object.method(cx, "assign")?;
+
+        assign.arg(this)?;
+
+        for source in sources {
+            assign.arg(*source)?;
+        }
+
+        assign.exec()
+    }
+
     #[cfg(feature = "napi-8")]
     fn freeze<'a, C: Context<'a>>(&self, cx: &mut C) -> NeonResult<&Self> {
         let env = cx.env().to_raw();
@@ -0,0 +1,291 @@
+use serde::de::{
+    self, value::StrDeserializer, DeserializeSeed, EnumAccess, IntoDeserializer, MapAccess,
+    SeqAccess, Unexpected, VariantAccess, Visitor,
+};
+
+use crate::{
+    context::Cx,
+    handle::Handle,
+    object::Object,
+    types::{
+        JsArray, JsBoolean, JsNull, JsNumber, JsObject, JsString, JsUndefined, JsValue, ValueType,
+    },
+};
+
+use super::Error;
+
+// Walks a JavaScript value, driving a serde `Visitor`
+pub(super) struct Deserializer<'a, 'cx> {
+    cx: &'a mut Cx<'cx>,
+    input: Handle<'cx, JsValue>,
+}
+
+impl<'a, 'cx> Deserializer<'a, 'cx> {
+    pub(super) fn new(cx: &'a mut Cx<'cx>, input: Handle<'cx, JsValue>) -> Self {
+        Self { cx, input }
+    }
+
+    fn is_nullish(&mut self) -> bool {
+        self.input.is_a::<JsUndefined, _>(self.cx) || self.input.is_a::<JsNull, _>(self.cx)
+    }
+}
+
+impl<'de> de::Deserializer<'de> for Deserializer<'_, '_> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        let cx = self.cx;
+        let input = self.input;
+
+        match input.type_of(cx) {
+            ValueType::Undefined | ValueType::Null => visitor.visit_unit(),
+            ValueType::Boolean => {
+                let v = input.downcast_or_throw::<JsBoolean, _>(cx)?;
+
+                visitor.visit_bool(v.value(cx))
+            }
+            ValueType::Number => {
+                let v = input.downcast_or_throw::<JsNumber, _>(cx)?;
+
+                visit_number(v.value(cx), visitor)
+            }
+            ValueType::String => {
+                let v = input.downcast_or_throw::<JsString, _>(cx)?;
+
+                visitor.visit_string(v.value(cx))
+            }
+            ValueType::Function => Err(de::Error::invalid_type(
+                Unexpected::Other("function"),
+                &visitor,
+            )),
+            ValueType::Object => {
+                if let Ok(v) = input.downcast::<JsArray, _>(cx) {
+                    let len = v.len(cx);
+
+                    return visitor.visit_seq(SeqDeserializer {
+                        cx,
+                        array: v,
+                        index: 0,
+                        len,
+                    });
+                }
+
+                let v = input.downcast_or_throw::<JsObject, _>(cx)?;
+                let keys = v.keys(cx)?;
+
+                visitor.visit_map(MapDeserializer {
+                    cx,
+                    object: v,
+                    keys: keys.into_iter(),
+                    key: None,
+                })
+            }
+            _ => Err(de::Error::invalid_type(
+                Unexpected::Other("JavaScript value"),
+                &visitor,
+            )),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(mut self, visitor: V) -> Result<V::Value, Error> {
+        if self.is_nullish() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    // Enums are externally tagged: unit variants are strings and all other variants
+    // are objects with a single key naming the variant
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        let cx = self.cx;
+        let input = self.input;
+
+        if let Ok(v) = input.downcast::<JsString, _>(cx) {
+            return visitor.visit_enum(v.value(cx).into_deserializer());
+        }
+
+        if let Ok(v) = input.downcast::<JsObject, _>(cx) {
+            let mut keys = v.keys(cx)?;
+
+            if keys.len() == 1 {
+                let variant = keys.remove(0);
+                let value = v.prop(cx, variant.as_str()).get()?;
+
+                return visitor.visit_enum(EnumDeserializer { cx, variant, value });
+            }
+        }
+
+        Err(de::Error::invalid_type(
+            Unexpected::Other("JavaScript value"),
+            &"a string or an object with a single key",
+        ))
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+        identifier ignored_any
+    }
+}
+
+// Whole numbers are visited as integers so that integer targets are range checked
+fn visit_number<'de, V: Visitor<'de>>(n: f64, visitor: V) -> Result<V::Value, Error> {
+    if n.fract() == 0.0 {
+        if (0.0..u64::MAX as f64).contains(&n) {
+            return visitor.visit_u64(n as u64);
+        }
+
+        if (i64::MIN as f64..0.0).contains(&n) {
+            return visitor.visit_i64(n as i64);
+        }
+    }
+
+    visitor.visit_f64(n)
+}
+
+struct SeqDeserializer<'a, 'cx> {
+    cx: &'a mut Cx<'cx>,
+    array: Handle<'cx, JsArray>,
+    index: u32,
+    len: u32,
+}
+
+impl<'de> SeqAccess<'de> for SeqDeserializer<'_, '_> {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Error> {
+        if self.index >= self.len {
+            return Ok(None);
+        }
+
+        let index = self.index;
+        let value = self.array.prop(self.cx, index).get()?;
+
+        self.index += 1;
+
+        seed.deserialize(Deserializer::new(self.cx, value))
+            .map(Some)
+            .map_err(|err| err.at_index(index))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some((self.len - self.index) as usize)
+    }
+}
+
+struct MapDeserializer<'a, 'cx> {
+    cx: &'a mut Cx<'cx>,
+    object: Handle<'cx, JsObject>,
+    keys: std::vec::IntoIter<String>,
+    key: Option<String>,
+}
+
+impl<'de> MapAccess<'de> for MapDeserializer<'_, '_> {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Error> {
+        let Some(key) = self.keys.next() else {
+            return Ok(None);
+        };
+
+        let value = seed.deserialize(StrDeserializer::<Error>::new(&key))?;
+
+        self.key = Some(key);
+
+        Ok(Some(value))
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Error> {
+        let key = self
+            .key
+            .take()
+            .ok_or_else(|| <Error as de::Error>::custom("value requested before key"))?;
+
+        let value = self.object.prop(self.cx, key.as_str()).get()?;
+
+        seed.deserialize(Deserializer::new(self.cx, value))
+            .map_err(|err| err.at_field(key))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.keys.len())
+    }
+}
+
+struct EnumDeserializer<'a, 'cx> {
+    cx: &'a mut Cx<'cx>,
+    variant: String,
+    value: Handle<'cx, JsValue>,
+}
+
+impl<'a, 'cx, 'de> EnumAccess<'de> for EnumDeserializer<'a, 'cx> {
+    type Error = Error;
+    type Variant = VariantDeserializer<'a, 'cx>;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, Self::Variant), Error> {
+        let variant = seed.deserialize(StrDeserializer::<Error>::new(&self.variant))?;
+
+        Ok((
+            variant,
+            VariantDeserializer {
+                inner: Deserializer::new(self.cx, self.value),
+                variant: self.variant,
+            },
+        ))
+    }
+}
+
+struct VariantDeserializer<'a, 'cx> {
+    inner: Deserializer<'a, 'cx>,
+    variant: String,
+}
+
+impl<'de> VariantAccess<'de> for VariantDeserializer<'_, '_> {
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<(), Error> {
+        de::Deserialize::deserialize(self.inner).map_err(|err: Error| err.at_field(self.variant))
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value, Error> {
+        seed.deserialize(self.inner)
+            .map_err(|err| err.at_field(self.variant))
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value, Error> {
+        de::Deserializer::deserialize_seq(self.inner, visitor)
+            .map_err(|err| err.at_field(self.variant))
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        de::Deserializer::deserialize_map(self.inner, visitor)
+            .map_err(|err| err.at_field(self.variant))
+    }
+}
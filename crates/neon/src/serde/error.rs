@@ -0,0 +1,114 @@
+use std::{error, fmt};
+
+use crate::{
+    context::Context,
+    result::{NeonResult, ResultExt, Throw},
+};
+
+/// Error converting between JavaScript values and Rust types with [`serde`](::serde).
+///
+/// Errors include the path to the value that failed to convert, e.g.
+/// ``invalid value: integer `300`, expected u8 at `config.retries` ``.
+#[derive(Debug)]
+pub struct Error {
+    kind: ErrorKind,
+    // Innermost segment first
+    path: Vec<Segment>,
+}
+
+#[derive(Debug)]
+enum ErrorKind {
+    Message(String),
+    Throw,
+}
+
+#[derive(Debug)]
+enum Segment {
+    Field(String),
+    Index(u32),
+}
+
+impl Error {
+    pub(super) fn at_field(mut self, field: impl Into<String>) -> Self {
+        self.path.push(Segment::Field(field.into()));
+        self
+    }
+
+    pub(super) fn at_index(mut self, index: u32) -> Self {
+        self.path.push(Segment::Index(index));
+        self
+    }
+
+    /// Returns `true` if the error was caused by a JavaScript exception, e.g. from a
+    /// getter. The exception is still pending and must be handled before calling back
+    /// into JavaScript.
+    pub fn is_throw(&self) -> bool {
+        matches!(self.kind, ErrorKind::Throw)
+    }
+
+    fn new(kind: ErrorKind) -> Self {
+        Self {
+            kind,
+            path: Vec::new(),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.kind {
+            ErrorKind::Message(msg) => f.write_str(msg)?,
+            ErrorKind::Throw => f.write_str("JavaScript exception")?,
+        }
+
+        if self.path.is_empty() {
+            return Ok(());
+        }
+
+        f.write_str(" at `")?;
+
+        for (i, segment) in self.path.iter().rev().enumerate() {
+            match segment {
+                Segment::Field(field) if i == 0 => f.write_str(field)?,
+                Segment::Field(field) => write!(f, ".{field}")?,
+                Segment::Index(index) => write!(f, "[{index}]")?,
+            }
+        }
+
+        f.write_str("`")
+    }
+}
+
+impl error::Error for Error {}
+
+impl serde::de::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Self::new(ErrorKind::Message(msg.to_string()))
+    }
+}
+
+impl serde::ser::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Self::new(ErrorKind::Message(msg.to_string()))
+    }
+}
+
+impl From<Throw> for Error {
+    fn from(_: Throw) -> Self {
+        Self::new(ErrorKind::Throw)
+    }
+}
+
+impl<T> ResultExt<T> for Result<T, Error> {
+    /// Throws a `TypeError` describing the conversion failure, or propagates the
+    /// pending exception if one was thrown during the conversion.
+    fn or_throw<'a, C: Context<'a>>(self, cx: &mut C) -> NeonResult<T> {
+        self.or_else(|err| {
+            if err.is_throw() {
+                return Err(unsafe { Throw::new() });
+            }
+
+            cx.throw_type_error(err.to_string())
+        })
+    }
+}
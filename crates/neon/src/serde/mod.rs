@@ -0,0 +1,145 @@
+//! Convert between JavaScript values and Rust types with [`serde`](::serde).
+//!
+//! Unlike the [`Json`](crate::types::extract::Json) extractor, which round-trips through
+//! `JSON.stringify` and `JSON.parse`, this module walks and builds JavaScript values directly.
+//!
+//! ```
+//! # use neon::prelude::*;
+//! # use serde::Deserialize;
+//! #[derive(Deserialize)]
+//! struct Config {
+//!     name: String,
+//!     retries: u8,
+//!     tags: Vec<String>,
+//!     timeout: Option<f64>,
+//! }
+//!
+//! fn configure(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+//!     let arg = cx.argument::<JsValue>(0)?;
+//!     let config: Config = neon::serde::from_value(&mut cx, arg).or_throw(&mut cx)?;
+//!
+//!     # let _ = config;
+//!     Ok(cx.undefined())
+//! }
+//! ```
+//!
+//! Serializing is the reverse, with [`to_value`]:
+//!
+//! ```
+//! # use neon::prelude::*;
+//! # use serde::Serialize;
+//! #[derive(Serialize)]
+//! struct Stats {
+//!     count: u32,
+//!     mean: Option<f64>,
+//! }
+//!
+//! fn stats(mut cx: FunctionContext) -> JsResult<JsValue> {
+//!     let stats = Stats { count: 0, mean: None };
+//!
+//!     neon::serde::to_value(&mut cx, &stats).or_throw(&mut cx)
+//! }
+//! ```
+//!
+//! ## Data model
+//!
+//! | JavaScript              | Rust                                      |
+//! | ----------------------- | ----------------------------------------- |
+//! | `undefined`, `null`     | `()`, `Option::None`                      |
+//! | `boolean`               | `bool`                                    |
+//! | `number`                | integers (range checked), `f32`, `f64`    |
+//! | `string`                | `String`, `char`, unit enum variants      |
+//! | `Array`                 | `Vec<T>`, tuples                          |
+//! | `Object`                | structs, maps, non-unit enum variants     |
+//! | `Buffer`                | bytes (serialize only)                    |
+//!
+//! Only own enumerable string keys of objects are visited, matching `Object.keys`. Enums
+//! use serde's externally tagged representation by default; the `tag`, `content`,
+//! and `untagged` attributes are also supported.
+//!
+//! When serializing, `Option::None` and `()` become `null` (see
+//! [`SerializeOptions::none_as_undefined`]), map keys must serialize to strings, and
+//! 64-bit integers must be within `Number.MAX_SAFE_INTEGER`.
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{
+    context::Cx,
+    handle::Handle,
+    types::{JsValue, Value},
+};
+
+pub use self::error::Error;
+
+mod de;
+mod error;
+mod ser;
+
+/// Deserialize a Rust value from a JavaScript value.
+///
+/// Returns an [`Error`] naming the path to the offending value if `value` does not match
+/// the shape of `T`. Use [`ResultExt::or_throw`](crate::result::ResultExt::or_throw) to
+/// throw the error as a `TypeError`.
+pub fn from_value<'cx, T, V>(cx: &mut Cx<'cx>, value: Handle<'cx, V>) -> Result<T, Error>
+where
+    T: DeserializeOwned,
+    V: Value,
+{
+    let value = value.upcast();
+
+    T::deserialize(de::Deserializer::new(cx, value))
+}
+
+/// Serialize a Rust value into a JavaScript value.
+///
+/// Equivalent to `SerializeOptions::new().to_value(cx, value)`.
+pub fn to_value<'cx, T>(cx: &mut Cx<'cx>, value: &T) -> Result<Handle<'cx, JsValue>, Error>
+where
+    T: Serialize + ?Sized,
+{
+    SerializeOptions::new().to_value(cx, value)
+}
+
+/// Options for serializing Rust values into JavaScript values.
+///
+/// ```
+/// # use neon::prelude::*;
+/// # use neon::serde::SerializeOptions;
+/// # fn example(mut cx: FunctionContext) -> JsResult<JsValue> {
+/// let value: Option<f64> = None;
+///
+/// SerializeOptions::new()
+///     .none_as_undefined(true)
+///     .to_value(&mut cx, &value)
+///     .or_throw(&mut cx)
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SerializeOptions {
+    none_as_undefined: bool,
+}
+
+impl SerializeOptions {
+    /// Creates options with the defaults used by [`to_value`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Serialize `Option::None` as `undefined` instead of `null`
+    pub fn none_as_undefined(mut self, none_as_undefined: bool) -> Self {
+        self.none_as_undefined = none_as_undefined;
+        self
+    }
+
+    /// Serialize a Rust value into a JavaScript value
+    pub fn to_value<'cx, T>(
+        self,
+        cx: &mut Cx<'cx>,
+        value: &T,
+    ) -> Result<Handle<'cx, JsValue>, Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(ser::Serializer::new(cx, self))
+    }
+}
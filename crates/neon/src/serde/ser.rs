@@ -0,0 +1,438 @@
+use serde::ser::{self, Serialize};
+
+use crate::{
+    context::{Context, Cx},
+    handle::Handle,
+    object::Object,
+    types::{JsArray, JsBuffer, JsObject, JsString, JsValue},
+};
+
+use super::{Error, SerializeOptions};
+
+// Largest integer that can be represented exactly by a JavaScript number
+const MAX_SAFE_INTEGER: u64 = (1 << 53) - 1;
+
+// Builds JavaScript values from a serde data model
+pub(super) struct Serializer<'a, 'cx> {
+    cx: &'a mut Cx<'cx>,
+    options: SerializeOptions,
+}
+
+impl<'a, 'cx> Serializer<'a, 'cx> {
+    pub(super) fn new(cx: &'a mut Cx<'cx>, options: SerializeOptions) -> Self {
+        Self { cx, options }
+    }
+
+    fn integer(self, v: i128) -> Result<Handle<'cx, JsValue>, Error> {
+        if v.unsigned_abs() > MAX_SAFE_INTEGER as u128 {
+            return Err(ser::Error::custom(format!(
+                "integer `{v}` cannot be represented exactly as a JavaScript number"
+            )));
+        }
+
+        Ok(self.cx.number(v as f64).upcast())
+    }
+}
+
+impl<'a, 'cx> ser::Serializer for Serializer<'a, 'cx> {
+    type Ok = Handle<'cx, JsValue>;
+    type Error = Error;
+
+    type SerializeSeq = ArraySerializer<'a, 'cx>;
+    type SerializeTuple = ArraySerializer<'a, 'cx>;
+    type SerializeTupleStruct = ArraySerializer<'a, 'cx>;
+    type SerializeTupleVariant = ArraySerializer<'a, 'cx>;
+    type SerializeMap = ObjectSerializer<'a, 'cx>;
+    type SerializeStruct = ObjectSerializer<'a, 'cx>;
+    type SerializeStructVariant = ObjectSerializer<'a, 'cx>;
+
+    fn serialize_bool(self, v: bool) -> Result<Self::Ok, Error> {
+        Ok(self.cx.boolean(v).upcast())
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Self::Ok, Error> {
+        Ok(self.cx.number(v).upcast())
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Self::Ok, Error> {
+        Ok(self.cx.number(v).upcast())
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Self::Ok, Error> {
+        Ok(self.cx.number(v).upcast())
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Self::Ok, Error> {
+        self.integer(v.into())
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<Self::Ok, Error> {
+        self.integer(v)
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Self::Ok, Error> {
+        Ok(self.cx.number(v).upcast())
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Self::Ok, Error> {
+        Ok(self.cx.number(v).upcast())
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Self::Ok, Error> {
+        Ok(self.cx.number(v).upcast())
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Self::Ok, Error> {
+        self.integer(v.into())
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<Self::Ok, Error> {
+        match i128::try_from(v) {
+            Ok(v) => self.integer(v),
+            Err(_) => Err(ser::Error::custom(format!(
+                "integer `{v}` cannot be represented exactly as a JavaScript number"
+            ))),
+        }
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Self::Ok, Error> {
+        Ok(self.cx.number(v).upcast())
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Self::Ok, Error> {
+        Ok(self.cx.number(v).upcast())
+    }
+
+    fn serialize_char(self, v: char) -> Result<Self::Ok, Error> {
+        self.serialize_str(v.encode_utf8(&mut [0; 4]))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Self::Ok, Error> {
+        Ok(self.cx.string(v).upcast())
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Self::Ok, Error> {
+        Ok(JsBuffer::from_slice(self.cx, v)?.upcast())
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Error> {
+        if self.options.none_as_undefined {
+            Ok(self.cx.undefined().upcast())
+        } else {
+            Ok(self.cx.null().upcast())
+        }
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<Self::Ok, Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Error> {
+        Ok(self.cx.null().upcast())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, Error> {
+        self.serialize_unit()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Self::Ok, Error> {
+        self.serialize_str(variant)
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Error> {
+        let options = self.options;
+        let cx = self.cx;
+        let value = value
+            .serialize(Serializer::new(cx, options))
+            .map_err(|err| err.at_field(variant))?;
+
+        wrap_variant(cx, Some(variant), value)
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, Error> {
+        Ok(ArraySerializer::new(self, len.unwrap_or(0), None))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, Error> {
+        Ok(ArraySerializer::new(self, len, None))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Error> {
+        Ok(ArraySerializer::new(self, len, None))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Error> {
+        Ok(ArraySerializer::new(self, len, Some(variant)))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Error> {
+        Ok(ObjectSerializer::new(self, None))
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, Error> {
+        Ok(ObjectSerializer::new(self, None))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Error> {
+        Ok(ObjectSerializer::new(self, Some(variant)))
+    }
+}
+
+// Externally tagged enum variants are wrapped in an object with a single key
+fn wrap_variant<'cx>(
+    cx: &mut Cx<'cx>,
+    variant: Option<&'static str>,
+    value: Handle<'cx, JsValue>,
+) -> Result<Handle<'cx, JsValue>, Error> {
+    let Some(variant) = variant else {
+        return Ok(value);
+    };
+
+    let object = cx.empty_object();
+
+    object.prop(cx, variant).set(value)?;
+
+    Ok(object.upcast())
+}
+
+pub(super) struct ArraySerializer<'a, 'cx> {
+    cx: &'a mut Cx<'cx>,
+    options: SerializeOptions,
+    array: Handle<'cx, JsArray>,
+    index: u32,
+    variant: Option<&'static str>,
+}
+
+impl<'a, 'cx> ArraySerializer<'a, 'cx> {
+    fn new(serializer: Serializer<'a, 'cx>, len: usize, variant: Option<&'static str>) -> Self {
+        let Serializer { cx, options } = serializer;
+        let array = JsArray::new(cx, len);
+
+        Self {
+            cx,
+            options,
+            array,
+            index: 0,
+            variant,
+        }
+    }
+
+    fn push<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        let index = self.index;
+        let value = value
+            .serialize(Serializer::new(self.cx, self.options))
+            .map_err(|err| err.at_index(index))?;
+
+        self.array.prop(self.cx, index).set(value)?;
+        self.index += 1;
+
+        Ok(())
+    }
+
+    fn finish(self) -> Result<Handle<'cx, JsValue>, Error> {
+        let value = self.array.upcast();
+
+        wrap_variant(self.cx, self.variant, value)
+    }
+}
+
+impl<'cx> ser::SerializeSeq for ArraySerializer<'_, 'cx> {
+    type Ok = Handle<'cx, JsValue>;
+    type Error = Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Error> {
+        self.finish()
+    }
+}
+
+impl<'cx> ser::SerializeTuple for ArraySerializer<'_, 'cx> {
+    type Ok = Handle<'cx, JsValue>;
+    type Error = Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Error> {
+        self.finish()
+    }
+}
+
+impl<'cx> ser::SerializeTupleStruct for ArraySerializer<'_, 'cx> {
+    type Ok = Handle<'cx, JsValue>;
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Error> {
+        self.finish()
+    }
+}
+
+impl<'cx> ser::SerializeTupleVariant for ArraySerializer<'_, 'cx> {
+    type Ok = Handle<'cx, JsValue>;
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Error> {
+        self.finish()
+    }
+}
+
+pub(super) struct ObjectSerializer<'a, 'cx> {
+    cx: &'a mut Cx<'cx>,
+    options: SerializeOptions,
+    object: Handle<'cx, JsObject>,
+    key: Option<Handle<'cx, JsString>>,
+    variant: Option<&'static str>,
+}
+
+impl<'a, 'cx> ObjectSerializer<'a, 'cx> {
+    fn new(serializer: Serializer<'a, 'cx>, variant: Option<&'static str>) -> Self {
+        let Serializer { cx, options } = serializer;
+        let object = cx.empty_object();
+
+        Self {
+            cx,
+            options,
+            object,
+            key: None,
+            variant,
+        }
+    }
+
+    fn set<T: ?Sized + Serialize>(
+        &mut self,
+        key: Handle<'cx, JsString>,
+        value: &T,
+    ) -> Result<(), Error> {
+        let value = value
+            .serialize(Serializer::new(self.cx, self.options))
+            .map_err(|err| err.at_field(key.value(self.cx)))?;
+
+        self.object.prop(self.cx, key).set(value)?;
+
+        Ok(())
+    }
+
+    fn finish(self) -> Result<Handle<'cx, JsValue>, Error> {
+        let value = self.object.upcast();
+
+        wrap_variant(self.cx, self.variant, value)
+    }
+}
+
+impl<'cx> ser::SerializeMap for ObjectSerializer<'_, 'cx> {
+    type Ok = Handle<'cx, JsValue>;
+    type Error = Error;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<(), Error> {
+        let key = key.serialize(Serializer::new(self.cx, self.options))?;
+        let key = key
+            .downcast::<JsString, _>(self.cx)
+            .map_err(|_| <Error as ser::Error>::custom("map keys must be strings"))?;
+
+        self.key = Some(key);
+
+        Ok(())
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        let key = self
+            .key
+            .take()
+            .ok_or_else(|| <Error as ser::Error>::custom("value serialized before key"))?;
+
+        self.set(key, value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Error> {
+        self.finish()
+    }
+}
+
+impl<'cx> ser::SerializeStruct for ObjectSerializer<'_, 'cx> {
+    type Ok = Handle<'cx, JsValue>;
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        let key = self.cx.string(key);
+
+        self.set(key, value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Error> {
+        self.finish()
+    }
+}
+
+impl<'cx> ser::SerializeStructVariant for ObjectSerializer<'_, 'cx> {
+    type Ok = Handle<'cx, JsValue>;
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        let key = self.cx.string(key);
+
+        self.set(key, value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Error> {
+        self.finish()
+    }
+}
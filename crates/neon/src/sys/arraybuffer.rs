@@ -83,3 +83,25 @@ pub unsafe fn size(env: Env, buf: Local) -> usize {
 
     size
 }
+
+/// Detaches an `ArrayBuffer`, returning `false` if it is not detachable
+/// # Safety
+/// * Caller must ensure `env` and `buf` are valid
+#[cfg(feature = "napi-7")]
+pub unsafe fn detach(env: Env, buf: Local) -> bool {
+    napi::detach_arraybuffer(env, buf) == napi::Status::Ok
+}
+
+/// # Safety
+/// * Caller must ensure `env` and `buf` are valid
+#[cfg(feature = "napi-7")]
+pub unsafe fn is_detached(env: Env, buf: Local) -> bool {
+    let mut result = false;
+
+    assert_eq!(
+        napi::is_detached_arraybuffer(env, buf, &mut result as *mut _),
+        napi::Status::Ok,
+    );
+
+    result
+}
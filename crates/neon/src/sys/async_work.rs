@@ -8,10 +8,13 @@
 //! See: [Async operations in Node-API](https://nodejs.org/api/n-api.html#n_api_simple_asynchronous_operations)
 
 use std::{
+    cell::Cell,
     ffi::c_void,
     mem,
     panic::{catch_unwind, resume_unwind, AssertUnwindSafe},
-    ptr, thread,
+    ptr,
+    rc::Rc,
+    thread,
 };
 
 use super::{
@@ -25,7 +28,26 @@ const BOUNDARY: FailureBoundary = FailureBoundary {
 };
 
 type Execute<I, O> = fn(input: I) -> O;
-type Complete<O, D> = fn(env: Env, output: thread::Result<O>, data: D);
+/// `output` is `None` if the work was cancelled before it executed
+type Complete<O, D> = fn(env: Env, output: Option<thread::Result<O>>, data: D);
+
+/// Handle to scheduled work that can be used to cancel it
+pub struct Work(Rc<Cell<Option<napi::AsyncWork>>>);
+
+impl Work {
+    /// Attempts to cancel the work, returning `true` if it had not started executing.
+    /// Cancelled work still calls `complete`.
+    ///
+    /// # Safety
+    /// * `env` must be a valid `napi_env` for the current thread
+    pub unsafe fn cancel(&self, env: Env) -> bool {
+        // `None` if the work has completed and been deleted
+        match self.0.get() {
+            Some(work) => napi::cancel_async_work(env, work) == napi::Status::Ok,
+            None => false,
+        }
+    }
+}
 
 /// Schedule work to execute on the libuv thread pool
 ///
@@ -39,16 +61,19 @@ pub unsafe fn schedule<I, O, D>(
     execute: Execute<I, O>,
     complete: Complete<O, D>,
     data: D,
-) where
+) -> Work
+where
     I: Send + 'static,
     O: Send + 'static,
     D: 'static,
 {
+    let handle = Rc::new(Cell::new(None));
     let mut data = Box::new(Data {
         state: State::Input(input),
         execute,
         complete,
         data: DebugSendWrapper::new(data),
+        handle: DebugSendWrapper::new(handle.clone()),
         // Work is initialized as a null pointer, but set by `create_async_work`
         // `data` must not be used until this value has been set.
         work: ptr::null_mut(),
@@ -80,6 +105,10 @@ pub unsafe fn schedule<I, O, D>(
             assert_eq!(status, napi::Status::Ok);
         }
     }
+
+    handle.set(Some(*work));
+
+    Work(handle)
 }
 
 /// A pointer to data is passed to the `execute` and `complete` callbacks
@@ -88,6 +117,8 @@ struct Data<I, O, D> {
     execute: Execute<I, O>,
     complete: Complete<O, D>,
     data: DebugSendWrapper<D>,
+    // Shared with `Work`; only accessed on the JavaScript main thread
+    handle: DebugSendWrapper<Rc<Cell<Option<napi::AsyncWork>>>>,
     work: napi::AsyncWork,
 }
 
@@ -148,23 +179,26 @@ unsafe extern "C" fn call_complete<I, O, D>(env: Env, status: napi::Status, data
         state,
         complete,
         data,
+        handle,
         work,
         ..
     } = *Box::<Data<I, O, D>>::from_raw(data.cast());
 
+    // The work can no longer be cancelled once deleted
+    handle.take().set(None);
     napi::delete_async_work(env, work);
 
     BOUNDARY.catch_failure(env, None, move |env| {
-        // `unwrap` is okay because `call_complete` should be called exactly once
-        // if and only if `call_execute` has completed successfully
-        let output = state.into_output().unwrap();
+        // `call_complete` is called exactly once, after `call_execute` has completed
+        // successfully or without calling `call_execute` if the work was cancelled
+        let output = state.into_output();
 
         // The event looped has stopped if we do not have an Env
         let env = if let Some(env) = env {
             env
         } else {
             // Resume panicking if necessary
-            if let Err(panic) = output {
+            if let Some(Err(panic)) = output {
                 resume_unwind(panic);
             }
 
@@ -172,8 +206,7 @@ unsafe extern "C" fn call_complete<I, O, D>(env: Env, status: napi::Status, data
         };
 
         match status {
-            napi::Status::Ok => complete(env, output, data.take()),
-            napi::Status::Cancelled => {}
+            napi::Status::Ok | napi::Status::Cancelled => complete(env, output, data.take()),
             _ => assert_eq!(status, napi::Status::Ok),
         }
 
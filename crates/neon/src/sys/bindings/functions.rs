@@ -31,6 +31,10 @@ mod napi1 {
 
             fn coerce_to_string(env: Env, value: Value, result: *mut Value) -> Status;
 
+            fn coerce_to_number(env: Env, value: Value, result: *mut Value) -> Status;
+
+            fn coerce_to_bool(env: Env, value: Value, result: *mut Value) -> Status;
+
             fn throw(env: Env, error: Value) -> Status;
 
             fn create_error(env: Env, code: Value, msg: Value, result: *mut Value) -> Status;
@@ -53,11 +57,15 @@ mod napi1 {
 
             fn is_arraybuffer(env: Env, value: Value, result: *mut bool) -> Status;
             fn is_typedarray(env: Env, value: Value, result: *mut bool) -> Status;
+            fn is_dataview(env: Env, value: Value, result: *mut bool) -> Status;
             fn is_buffer(env: Env, value: Value, result: *mut bool) -> Status;
             fn is_error(env: Env, value: Value, result: *mut bool) -> Status;
             fn is_array(env: Env, value: Value, result: *mut bool) -> Status;
             fn is_promise(env: Env, value: Value, result: *mut bool) -> Status;
 
+            fn instanceof(env: Env, object: Value, constructor: Value, result: *mut bool)
+                -> Status;
+
             fn get_value_string_utf8(
                 env: Env,
                 value: Value,
@@ -77,6 +85,14 @@ mod napi1 {
                 result: *mut usize,
             ) -> Status;
 
+            fn get_value_string_latin1(
+                env: Env,
+                value: Value,
+                buf: *mut c_char,
+                bufsize: usize,
+                result: *mut usize,
+            ) -> Status;
+
             fn create_type_error(env: Env, code: Value, msg: Value, result: *mut Value) -> Status;
 
             fn create_range_error(env: Env, code: Value, msg: Value, result: *mut Value) -> Status;
@@ -88,6 +104,22 @@ mod napi1 {
                 result: *mut Value,
             ) -> Status;
 
+            fn create_symbol(env: Env, description: Value, result: *mut Value) -> Status;
+
+            fn create_string_utf16(
+                env: Env,
+                str: *const u16,
+                length: usize,
+                result: *mut Value,
+            ) -> Status;
+
+            fn create_string_latin1(
+                env: Env,
+                str: *const c_char,
+                length: usize,
+                result: *mut Value,
+            ) -> Status;
+
             fn create_arraybuffer(
                 env: Env,
                 byte_length: usize,
@@ -121,6 +153,23 @@ mod napi1 {
                 offset: *mut usize,
             ) -> Status;
 
+            fn create_dataview(
+                env: Env,
+                length: usize,
+                arraybuffer: Value,
+                byte_offset: usize,
+                result: *mut Value,
+            ) -> Status;
+
+            fn get_dataview_info(
+                env: Env,
+                dataview: Value,
+                bytelength: *mut usize,
+                data: *mut *mut c_void,
+                arraybuffer: *mut Value,
+                byte_offset: *mut usize,
+            ) -> Status;
+
             fn create_buffer(
                 env: Env,
                 length: usize,
@@ -144,6 +193,19 @@ mod napi1 {
                 data: *mut *mut c_void,
             ) -> Status;
 
+            #[cfg(any(feature = "sys", feature = "napi-8"))]
+            fn wrap(
+                env: Env,
+                js_object: Value,
+                native_object: *mut c_void,
+                finalize_cb: Finalize,
+                finalize_hint: *mut c_void,
+                result: *mut Ref,
+            ) -> Status;
+
+            #[cfg(any(feature = "sys", feature = "napi-8"))]
+            fn unwrap(env: Env, js_object: Value, result: *mut *mut c_void) -> Status;
+
             fn create_external(
                 env: Env,
                 data: *mut c_void,
@@ -182,6 +244,10 @@ mod napi1 {
 
             fn get_property(env: Env, object: Value, key: Value, result: *mut Value) -> Status;
 
+            fn has_own_property(env: Env, object: Value, key: Value, result: *mut bool) -> Status;
+
+            fn get_prototype(env: Env, object: Value, result: *mut Value) -> Status;
+
             fn set_element(env: Env, object: Value, index: u32, value: Value) -> Status;
 
             fn get_element(env: Env, object: Value, index: u32, result: *mut Value) -> Status;
@@ -210,6 +276,12 @@ mod napi1 {
 
             fn strict_equals(env: Env, lhs: Value, rhs: Value, result: *mut bool) -> Status;
 
+            fn adjust_external_memory(
+                env: Env,
+                change_in_bytes: i64,
+                adjusted_value: *mut i64,
+            ) -> Status;
+
             #[cfg(any(feature = "sys", feature = "external-buffers"))]
             fn create_external_arraybuffer(
                 env: Env,
@@ -244,6 +316,7 @@ mod napi1 {
 
             fn delete_async_work(env: Env, work: AsyncWork) -> Status;
             fn queue_async_work(env: Env, work: AsyncWork) -> Status;
+            fn cancel_async_work(env: Env, work: AsyncWork) -> Status;
             fn create_promise(env: Env, deferred: *mut Deferred, promise: *mut Value) -> Status;
             fn resolve_deferred(env: Env, deferred: Deferred, resolution: Value) -> Status;
             fn reject_deferred(env: Env, deferred: Deferred, rejection: Value) -> Status;
@@ -258,6 +331,21 @@ mod napi1 {
     );
 }
 
+#[cfg(feature = "napi-3")]
+mod napi3 {
+    use super::super::types::*;
+    use std::os::raw::c_void;
+
+    generate!(
+        #[cfg_attr(docsrs, doc(cfg(feature = "napi-3")))]
+        extern "C" {
+            fn add_env_cleanup_hook(env: Env, fun: CleanupHook, arg: *mut c_void) -> Status;
+
+            fn remove_env_cleanup_hook(env: Env, fun: CleanupHook, arg: *mut c_void) -> Status;
+        }
+    );
+}
+
 #[cfg(feature = "napi-4")]
 mod napi4 {
     use super::super::types::*;
@@ -387,6 +475,19 @@ mod napi6 {
     );
 }
 
+#[cfg(feature = "napi-7")]
+mod napi7 {
+    use super::super::types::*;
+
+    generate!(
+        #[cfg_attr(docsrs, doc(cfg(feature = "napi-7")))]
+        extern "C" {
+            fn detach_arraybuffer(env: Env, value: Value) -> Status;
+            fn is_detached_arraybuffer(env: Env, value: Value, result: *mut bool) -> Status;
+        }
+    );
+}
+
 #[cfg(feature = "napi-8")]
 mod napi8 {
     use super::super::types::*;
@@ -408,17 +509,30 @@ mod napi8 {
 }
 
 pub use napi1::*;
+#[cfg(feature = "napi-3")]
+pub use napi3::*;
 #[cfg(feature = "napi-4")]
 pub use napi4::*;
 #[cfg(feature = "napi-5")]
 pub use napi5::*;
 #[cfg(feature = "napi-6")]
 pub use napi6::*;
+#[cfg(feature = "napi-7")]
+pub use napi7::*;
 #[cfg(feature = "napi-8")]
 pub use napi8::*;
 
+use std::sync::atomic::{AtomicU32, Ordering};
+
 use super::{Env, Status};
 
+// The Node-API version of the host process, recorded when the symbols are loaded
+static NAPI_VERSION: AtomicU32 = AtomicU32::new(0);
+
+pub(crate) fn napi_version() -> u32 {
+    NAPI_VERSION.load(Ordering::Relaxed)
+}
+
 // This symbol is loaded separately because it is a prerequisite
 unsafe fn get_version(host: &libloading::Library, env: Env) -> Result<u32, libloading::Error> {
     let get_version = host.get::<fn(Env, *mut u32) -> Status>(b"napi_get_version")?;
@@ -439,6 +553,8 @@ pub(crate) unsafe fn load(env: Env) -> Result<(), libloading::Error> {
     // with `Error: Module did not self-register` if N-API does not exist.
     let actual_version = get_version(&host, env).expect("Failed to find N-API version");
 
+    NAPI_VERSION.store(actual_version, Ordering::Relaxed);
+
     let expected_version = match () {
         _ if cfg!(feature = "napi-8") => 8,
         _ if cfg!(feature = "napi-7") => 7,
@@ -456,6 +572,9 @@ pub(crate) unsafe fn load(env: Env) -> Result<(), libloading::Error> {
 
     napi1::load(&host);
 
+    #[cfg(feature = "napi-3")]
+    napi3::load(&host);
+
     #[cfg(feature = "napi-4")]
     napi4::load(&host);
 
@@ -465,6 +584,9 @@ pub(crate) unsafe fn load(env: Env) -> Result<(), libloading::Error> {
     #[cfg(feature = "napi-6")]
     napi6::load(&host);
 
+    #[cfg(feature = "napi-7")]
+    napi7::load(&host);
+
     #[cfg(feature = "napi-8")]
     napi8::load(&host);
 
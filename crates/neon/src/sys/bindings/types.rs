@@ -88,6 +88,11 @@ pub type Callback = Option<unsafe extern "C" fn(env: Env, info: CallbackInfo) ->
 pub type Finalize =
     Option<unsafe extern "C" fn(env: Env, finalize_data: *mut c_void, finalize_hint: *mut c_void)>;
 
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-3")))]
+#[cfg(feature = "napi-3")]
+/// [`napi_add_env_cleanup_hook`](https://nodejs.org/api/n-api.html#napi_add_env_cleanup_hook)
+pub type CleanupHook = Option<unsafe extern "C" fn(arg: *mut c_void)>;
+
 #[cfg_attr(docsrs, doc(cfg(feature = "napi-4")))]
 #[cfg(feature = "napi-4")]
 /// [`napi_threadsafe_function_call_js`](https://nodejs.org/api/n-api.html#napi_threadsafe_function_call_js)
@@ -218,6 +223,7 @@ pub struct KeyFilter(pub ::std::os::raw::c_uint);
 impl KeyFilter {
     pub const ALL_PROPERTIES: KeyFilter = KeyFilter(0);
     pub const WRITABLE: KeyFilter = KeyFilter(1);
+    pub const ENUMERABLE: KeyFilter = KeyFilter(2);
     pub const CONFIGURABLE: KeyFilter = KeyFilter(4);
     pub const SKIP_STRINGS: KeyFilter = KeyFilter(8);
     pub const SKIP_SYMBOLS: KeyFilter = KeyFilter(16);
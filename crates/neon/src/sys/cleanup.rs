@@ -0,0 +1,90 @@
+//! Environment cleanup hooks
+//!
+//! See: [Cleanup on exit of the current Node.js environment](https://nodejs.org/api/n-api.html#cleanup-on-exit-of-the-current-nodejs-environment)
+
+use std::{
+    cell::Cell,
+    os::raw::c_void,
+    panic::{catch_unwind, AssertUnwindSafe},
+    ptr::NonNull,
+    rc::Rc,
+};
+
+use super::{bindings as napi, no_panic::panic_msg, raw::Env};
+
+struct Hook {
+    // Set when the hook runs, after which `f` has been freed
+    ran: Rc<Cell<bool>>,
+    f: Box<dyn FnOnce() + 'static>,
+}
+
+/// A registered cleanup hook. The closure is owned by Node-API until the hook
+/// either runs or is removed.
+pub struct CleanupHook {
+    env: Env,
+    hook: NonNull<Hook>,
+    ran: Rc<Cell<bool>>,
+}
+
+/// Registers a closure to run when `env` is torn down
+///
+/// # Safety
+/// `env` must point to a valid `napi_env` for this thread
+pub unsafe fn add<F: FnOnce() + 'static>(env: Env, f: F) -> CleanupHook {
+    let ran = Rc::new(Cell::new(false));
+    let hook = Box::into_raw(Box::new(Hook {
+        ran: ran.clone(),
+        f: Box::new(f),
+    }));
+
+    assert_eq!(
+        napi::add_env_cleanup_hook(env, Some(call_hook), hook.cast()),
+        napi::Status::Ok,
+    );
+
+    CleanupHook {
+        env,
+        hook: NonNull::new_unchecked(hook),
+        ran,
+    }
+}
+
+/// Removes a cleanup hook without running it. Returns `false` if the hook already ran.
+///
+/// # Safety
+/// `env` must point to a valid `napi_env` for this thread
+pub unsafe fn remove(env: Env, hook: CleanupHook) -> bool {
+    if hook.ran.get() {
+        return false;
+    }
+
+    assert_eq!(
+        env, hook.env,
+        "Cleanup hook removed from a different environment"
+    );
+
+    let ptr = hook.hook.as_ptr();
+
+    assert_eq!(
+        napi::remove_env_cleanup_hook(env, Some(call_hook), ptr.cast()),
+        napi::Status::Ok,
+    );
+
+    drop(Box::from_raw(ptr));
+
+    true
+}
+
+unsafe extern "C" fn call_hook(arg: *mut c_void) {
+    let Hook { ran, f } = *Box::from_raw(arg.cast::<Hook>());
+
+    ran.set(true);
+
+    // Unwinding across FFI would abort. JavaScript cannot be called during cleanup, so
+    // the panic is reported and the remaining hooks still run.
+    if let Err(panic) = catch_unwind(AssertUnwindSafe(f)) {
+        let msg = panic_msg(&panic).unwrap_or("Unknown panic");
+
+        eprintln!("Warning: panic in a neon cleanup hook: {msg}");
+    }
+}
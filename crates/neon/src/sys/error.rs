@@ -49,27 +49,30 @@ pub unsafe fn throw(env: Env, val: Local) {
     assert_eq!(status, napi::Status::Ok);
 }
 
-pub unsafe fn new_error(env: Env, out: &mut Local, msg: Local) {
+// `code` may be null to create an error without a `code` property
+pub unsafe fn new_error(env: Env, out: &mut Local, code: Local, msg: Local) {
     let mut result = MaybeUninit::uninit();
-    let status = napi::create_error(env, ptr::null_mut(), msg, result.as_mut_ptr());
+    let status = napi::create_error(env, code, msg, result.as_mut_ptr());
 
     assert_eq!(status, napi::Status::Ok);
 
     *out = result.assume_init();
 }
 
-pub unsafe fn new_type_error(env: Env, out: &mut Local, msg: Local) {
+// `code` may be null to create an error without a `code` property
+pub unsafe fn new_type_error(env: Env, out: &mut Local, code: Local, msg: Local) {
     let mut result = MaybeUninit::uninit();
-    let status = napi::create_type_error(env, ptr::null_mut(), msg, result.as_mut_ptr());
+    let status = napi::create_type_error(env, code, msg, result.as_mut_ptr());
 
     assert_eq!(status, napi::Status::Ok);
 
     *out = result.assume_init();
 }
 
-pub unsafe fn new_range_error(env: Env, out: &mut Local, msg: Local) {
+// `code` may be null to create an error without a `code` property
+pub unsafe fn new_range_error(env: Env, out: &mut Local, code: Local, msg: Local) {
     let mut result = MaybeUninit::uninit();
-    let status = napi::create_range_error(env, ptr::null_mut(), msg, result.as_mut_ptr());
+    let status = napi::create_range_error(env, code, msg, result.as_mut_ptr());
 
     assert_eq!(status, napi::Status::Ok);
 
@@ -87,3 +87,51 @@ pub unsafe fn create<T: 'static>(env: Env, v: T, finalizer: fn(Env, T)) -> Local
 
     external
 }
+
+#[cfg(feature = "napi-8")]
+/// `finalize_pointer` is invoked immediately before a `napi_external` created with
+/// `create_pointer` is garbage collected
+extern "C" fn finalize_pointer<T>(
+    _env: Env,
+    // Raw pointer stored by a `napi_external`
+    data: *mut std::ffi::c_void,
+    // Pointer to a Rust `fn` stored in the `hint` parameter of a `napi_external`
+    hint: *mut std::ffi::c_void,
+) {
+    unsafe {
+        let finalizer: fn(*mut T) = std::mem::transmute(hint as *const ());
+
+        finalizer(data.cast());
+    }
+}
+
+#[cfg(feature = "napi-8")]
+/// Creates a `napi_external` from a raw pointer, without taking ownership of it
+pub unsafe fn create_pointer<T>(env: Env, ptr: *mut T, finalizer: fn(*mut T)) -> Local {
+    let mut result = MaybeUninit::uninit();
+
+    let status = napi::create_external(
+        env,
+        ptr.cast(),
+        Some(finalize_pointer::<T>),
+        finalizer as *const () as *mut _,
+        result.as_mut_ptr(),
+    );
+
+    // `napi_create_external` will only fail if the VM is in a throwing state
+    // or shutting down.
+    assert_eq!(status, napi::Status::Ok);
+
+    result.assume_init()
+}
+
+#[cfg(feature = "napi-8")]
+/// Returns the raw pointer stored in a `napi_external`
+pub unsafe fn pointer(env: Env, local: Local) -> *mut std::ffi::c_void {
+    let mut result = MaybeUninit::uninit();
+    let status = napi::get_value_external(env, local, result.as_mut_ptr());
+
+    assert_eq!(status, napi::Status::Ok);
+
+    result.assume_init()
+}
@@ -11,3 +11,14 @@ pub unsafe fn strict_equals(env: Env, lhs: Local, rhs: Local) -> bool {
     );
     result
 }
+
+/// Informs the garbage collector of `change` bytes of memory kept alive by JavaScript
+/// objects, returning the adjusted total.
+pub unsafe fn adjust_external_memory(env: Env, change: i64) -> i64 {
+    let mut result = 0;
+    assert_eq!(
+        napi::adjust_external_memory(env, change, &mut result as *mut _),
+        napi::Status::Ok
+    );
+    result
+}
@@ -76,7 +76,10 @@ pub(crate) mod arraybuffer;
 pub(crate) mod async_work;
 pub(crate) mod buffer;
 pub(crate) mod call;
+#[cfg(feature = "napi-3")]
+pub(crate) mod cleanup;
 pub(crate) mod convert;
+pub(crate) mod dataview;
 pub(crate) mod error;
 pub(crate) mod external;
 pub(crate) mod fun;
@@ -91,6 +94,8 @@ pub(crate) mod scope;
 pub(crate) mod string;
 pub(crate) mod tag;
 pub(crate) mod typedarray;
+#[cfg(feature = "napi-8")]
+pub(crate) mod wrap;
 
 pub mod bindings;
 
@@ -242,7 +242,7 @@ unsafe fn set_property(env: Env, object: Local, key: &str, value: Local) {
 }
 
 #[track_caller]
-unsafe fn panic_msg(panic: &Panic) -> Option<&str> {
+pub(super) unsafe fn panic_msg(panic: &Panic) -> Option<&str> {
     if let Some(msg) = panic.downcast_ref::<&str>() {
         Some(msg)
     } else if let Some(msg) = panic.downcast_ref::<String>() {
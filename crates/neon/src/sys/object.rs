@@ -29,13 +29,33 @@ pub unsafe fn seal(env: Env, obj: Local) -> napi::Status {
 /// Mutates the `out` argument to refer to a `napi_value` containing the own property names of the
 /// `object` as a JavaScript Array.
 pub unsafe fn get_own_property_names(out: &mut Local, env: Env, object: Local) -> bool {
+    get_property_names(
+        out,
+        env,
+        object,
+        napi::KeyCollectionMode::OwnOnly,
+        napi::KeyFilter::ALL_PROPERTIES | napi::KeyFilter::SKIP_SYMBOLS,
+    )
+}
+
+#[cfg(feature = "napi-6")]
+/// Mutates the `out` argument to refer to a `napi_value` containing the property names of the
+/// `object` as a JavaScript Array, selected by `mode` and `filter`. Numeric keys are converted
+/// to strings.
+pub unsafe fn get_property_names(
+    out: &mut Local,
+    env: Env,
+    object: Local,
+    mode: napi::KeyCollectionMode,
+    filter: napi::KeyFilter,
+) -> bool {
     let mut property_names = MaybeUninit::uninit();
 
     if napi::get_all_property_names(
         env,
         object,
-        napi::KeyCollectionMode::OwnOnly,
-        napi::KeyFilter::ALL_PROPERTIES | napi::KeyFilter::SKIP_SYMBOLS,
+        mode,
+        filter,
         napi::KeyConversion::NumbersToStrings,
         property_names.as_mut_ptr(),
     ) != napi::Status::Ok
@@ -136,6 +156,18 @@ pub unsafe fn get(out: &mut Local, env: Env, object: Local, key: Local) -> bool
     status == napi::Status::Ok
 }
 
+/// Mutates `out` to refer to the prototype of `object`. Returns false if the prototype
+/// couldn't be retrieved.
+pub unsafe fn get_prototype(out: &mut Local, env: Env, object: Local) -> bool {
+    napi::get_prototype(env, object, out as *mut _) == napi::Status::Ok
+}
+
+/// Checks if `object` has an own property named by the `key` value, without checking the
+/// prototype chain. The key must be a string or symbol.
+pub unsafe fn has_own(out: &mut bool, env: Env, object: Local, key: Local) -> napi::Status {
+    napi::has_own_property(env, object, key, out as *mut _)
+}
+
 /// Sets the property value of an `napi_value` object, named by another `value` `key`. Returns `true` if the set succeeded.
 ///
 /// The `out` parameter and the return value contain the same information for historical reasons,
@@ -45,3 +45,12 @@ pub unsafe fn number_value(env: Env, p: Local) -> f64 {
     );
     value
 }
+
+/// Mutates the `out` argument provided to refer to a newly created unique symbol. The
+/// `description` must be a string or `null`.
+pub unsafe fn symbol(out: &mut Local, env: Env, description: Local) {
+    assert_eq!(
+        napi::create_symbol(env, description, out as *mut Local),
+        napi::Status::Ok
+    );
+}
@@ -5,11 +5,11 @@ use super::{
     raw::{Env, Local},
 };
 
-pub unsafe fn new(env: Env, value: Local) -> napi::Ref {
+pub unsafe fn new(env: Env, value: Local, count: u32) -> napi::Ref {
     let mut result = MaybeUninit::uninit();
 
     assert_eq!(
-        napi::create_reference(env, value, 1, result.as_mut_ptr()),
+        napi::create_reference(env, value, count, result.as_mut_ptr()),
         napi::Status::Ok,
     );
 
@@ -44,6 +44,32 @@ pub unsafe fn unreference(env: Env, value: napi::Ref) {
     }
 }
 
+/// Decrements the reference count without deleting the reference, returning the new count
+///
+/// # Safety
+/// Must only be used from the same module context that created the reference
+#[cfg(feature = "napi-6")]
+pub unsafe fn decrement(env: Env, value: napi::Ref) -> u32 {
+    let mut result = MaybeUninit::uninit();
+
+    assert_eq!(
+        napi::reference_unref(env, value, result.as_mut_ptr()),
+        napi::Status::Ok,
+    );
+
+    result.assume_init()
+}
+
+/// # Safety
+/// Must only be used from the same module context that created the reference
+#[cfg(feature = "napi-6")]
+pub unsafe fn delete(env: Env, value: napi::Ref) {
+    assert_eq!(napi::delete_reference(env, value), napi::Status::Ok);
+}
+
+/// Returns the referenced value, which is null if the reference is weak and the
+/// value has been collected
+///
 /// # Safety
 /// Must only be used from the same module context that created the reference
 pub unsafe fn get(env: Env, value: napi::Ref) -> Local {
@@ -1,16 +1,23 @@
+use std::mem::MaybeUninit;
+
 use super::{
     bindings as napi,
     raw::{Env, Local},
 };
 
-/// Return true if an `napi_value` `val` has the expected value type.
-unsafe fn is_type(env: Env, val: Local, expect: napi::ValueType) -> bool {
+/// Returns the result of `napi_typeof` for `val`
+pub unsafe fn type_of(env: Env, val: Local) -> napi::ValueType {
     let mut actual = napi::ValueType::Undefined;
     assert_eq!(
         napi::typeof_value(env, val, &mut actual as *mut _),
         napi::Status::Ok
     );
-    actual == expect
+    actual
+}
+
+/// Return true if an `napi_value` `val` has the expected value type.
+unsafe fn is_type(env: Env, val: Local, expect: napi::ValueType) -> bool {
+    type_of(env, val) == expect
 }
 
 pub unsafe fn is_undefined(env: Env, val: Local) -> bool {
@@ -21,6 +28,14 @@ pub unsafe fn is_null(env: Env, val: Local) -> bool {
     is_type(env, val, napi::ValueType::Null)
 }
 
+/// Is `val` either `null` or `undefined`? Performs a single `napi_typeof` call.
+pub unsafe fn is_nullish(env: Env, val: Local) -> bool {
+    matches!(
+        type_of(env, val),
+        napi::ValueType::Undefined | napi::ValueType::Null
+    )
+}
+
 /// Is `val` a JavaScript number?
 pub unsafe fn is_number(env: Env, val: Local) -> bool {
     is_type(env, val, napi::ValueType::Number)
@@ -31,6 +46,11 @@ pub unsafe fn is_boolean(env: Env, val: Local) -> bool {
     is_type(env, val, napi::ValueType::Boolean)
 }
 
+/// Is `val` a JavaScript symbol?
+pub unsafe fn is_symbol(env: Env, val: Local) -> bool {
+    is_type(env, val, napi::ValueType::Symbol)
+}
+
 /// Is `val` a JavaScript string?
 pub unsafe fn is_string(env: Env, val: Local) -> bool {
     is_type(env, val, napi::ValueType::String)
@@ -40,6 +60,11 @@ pub unsafe fn is_object(env: Env, val: Local) -> bool {
     is_type(env, val, napi::ValueType::Object)
 }
 
+#[cfg(feature = "napi-8")]
+pub unsafe fn is_external(env: Env, val: Local) -> bool {
+    is_type(env, val, napi::ValueType::External)
+}
+
 pub unsafe fn is_array(env: Env, val: Local) -> bool {
     let mut result = false;
     assert_eq!(
@@ -92,6 +117,16 @@ pub unsafe fn is_typedarray(env: Env, val: Local) -> bool {
     result
 }
 
+/// Is `val` a DataView instance?
+pub unsafe fn is_dataview(env: Env, val: Local) -> bool {
+    let mut result = false;
+    assert_eq!(
+        napi::is_dataview(env, val, &mut result as *mut _),
+        napi::Status::Ok
+    );
+    result
+}
+
 #[cfg(feature = "napi-5")]
 pub unsafe fn is_date(env: Env, val: Local) -> bool {
     let mut result = false;
@@ -115,6 +150,65 @@ pub unsafe fn is_promise(env: Env, val: Local) -> bool {
     result
 }
 
+/// Is `val` an instance of the global constructor `name`, e.g. `Map`? Returns `false`
+/// if the global does not exist or is not a function.
+///
+/// Reading the global and evaluating `instanceof` may run user code that throws. In that
+/// case `false` is returned and the exception is left pending to be propagated by the
+/// caller. Nothing is evaluated and `false` is returned if an exception is already
+/// pending.
+///
+/// # Safety
+/// * `env` is a valid `napi_env` for the current thread
+pub unsafe fn is_instance_of_global(env: Env, val: Local, name: &str) -> bool {
+    if super::error::is_throwing(env) || !is_object(env, val) {
+        return false;
+    }
+
+    let mut global = MaybeUninit::uninit();
+    let mut constructor = MaybeUninit::uninit();
+
+    assert_eq!(napi::get_global(env, global.as_mut_ptr()), napi::Status::Ok);
+
+    if !super::object::get_string(
+        env,
+        &mut *constructor.as_mut_ptr(),
+        global.assume_init(),
+        name.as_ptr(),
+        name.len() as i32,
+    ) {
+        return false;
+    }
+
+    let constructor = constructor.assume_init();
+
+    if !is_function(env, constructor) {
+        return false;
+    }
+
+    let mut result = false;
+
+    if instance_of(env, val, constructor, &mut result) != napi::Status::Ok {
+        return false;
+    }
+
+    result
+}
+
+/// Evaluates `val instanceof constructor`. The `out` parameter is only valid when the
+/// returned status is `Ok`.
+///
+/// # Safety
+/// * `env` is a valid `napi_env` for the current thread
+pub unsafe fn instance_of(
+    env: Env,
+    val: Local,
+    constructor: Local,
+    out: &mut bool,
+) -> napi::Status {
+    napi::instanceof(env, val, constructor, out as *mut _)
+}
+
 #[cfg(feature = "napi-8")]
 pub unsafe fn type_tag_object(env: Env, object: Local, tag: &super::TypeTag) {
     assert_eq!(
@@ -44,13 +44,21 @@ impl<T: Send + 'static> ThreadsafeFunction<T> {
     /// Creates a new unbounded N-API Threadsafe Function
     /// Safety: `Env` must be valid for the current thread
     pub unsafe fn new(env: Env, callback: fn(Option<Env>, T)) -> Self {
-        Self::with_capacity(env, 0, callback)
+        Self::named(env, "neon threadsafe function", callback)
+    }
+
+    /// Creates a new unbounded N-API Threadsafe Function with an async resource `name`
+    /// that identifies it in diagnostics, e.g. `async_hooks`
+    /// Safety: `Env` must be valid for the current thread
+    pub unsafe fn named(env: Env, name: &str, callback: fn(Option<Env>, T)) -> Self {
+        Self::with_capacity(env, name, 0, callback)
     }
 
     /// Creates a bounded N-API Threadsafe Function
     /// Safety: `Env` must be valid for the current thread
     pub unsafe fn with_capacity(
         env: Env,
+        name: &str,
         max_queue_size: usize,
         callback: fn(Option<Env>, T),
     ) -> Self {
@@ -62,7 +70,7 @@ impl<T: Send + 'static> ThreadsafeFunction<T> {
                 env,
                 std::ptr::null_mut(),
                 std::ptr::null_mut(),
-                super::string(env, "neon threadsafe function"),
+                super::string(env, name),
                 max_queue_size,
                 // Always set the reference count to 1. Prefer using
                 // Rust `Arc` to maintain the struct.
@@ -0,0 +1,70 @@
+use std::{mem::MaybeUninit, ptr};
+
+use super::{
+    bindings as napi,
+    debug_send_wrapper::DebugSendWrapper,
+    raw::{Env, Local},
+};
+
+/// `finalize_wrap` is invoked immediately before a wrapped object is garbage collected
+extern "C" fn finalize_wrap<T: 'static>(
+    _env: Env,
+    // Raw pointer to a `Box<T>` stored by `napi_wrap`
+    data: *mut std::ffi::c_void,
+    _hint: *mut std::ffi::c_void,
+) {
+    unsafe {
+        drop(Box::<DebugSendWrapper<T>>::from_raw(data as *mut _));
+    }
+}
+
+/// Associates a Rust value with a JavaScript object. The value is dropped when the
+/// object is garbage collected.
+///
+/// Returns `false` if the object could not be wrapped, e.g. because it is already wrapped.
+pub unsafe fn wrap<T: 'static>(env: Env, object: Local, v: T) -> bool {
+    let v = Box::into_raw(Box::new(DebugSendWrapper::new(v)));
+    let status = napi::wrap(
+        env,
+        object,
+        v as *mut _,
+        Some(finalize_wrap::<T>),
+        ptr::null_mut(),
+        ptr::null_mut(),
+    );
+
+    if status != napi::Status::Ok {
+        drop(Box::from_raw(v));
+
+        return false;
+    }
+
+    // Tag the object as coming from this module
+    super::tag::type_tag_object(env, object, &crate::MODULE_TAG);
+
+    true
+}
+
+/// Returns a pointer to data associated with an object by [`wrap`]. Returns `None` for
+/// objects that were not wrapped by this module, which are identified by a type tag.
+pub unsafe fn unwrap<T: 'static>(env: Env, object: Local) -> Option<*const T> {
+    if !super::tag::is_object(env, object) {
+        return None;
+    }
+
+    // Check the object was wrapped by this module
+    if !super::tag::check_object_type_tag(env, object, &crate::MODULE_TAG) {
+        return None;
+    }
+
+    let mut result = MaybeUninit::uninit();
+
+    if napi::unwrap(env, object, result.as_mut_ptr()) != napi::Status::Ok {
+        return None;
+    }
+
+    let v = result.assume_init();
+    let v = &**v.cast_const().cast::<DebugSendWrapper<T>>() as *const T;
+
+    Some(v)
+}
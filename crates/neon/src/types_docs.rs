@@ -69,11 +69,13 @@
 ///     JsBoolean(JsBoolean)
 ///     JsNumber(JsNumber)
 ///     JsString(JsString)
+///     JsSymbol(JsSymbol)
 ///     JsNull(JsNull)
 ///     JsUndefined(JsUndefined)
 ///     click JsBoolean "./struct.JsBoolean.html" "JsBoolean"
 ///     click JsNumber "./struct.JsNumber.html" "JsNumber"
 ///     click JsString "./struct.JsString.html" "JsString"
+///     click JsSymbol "./struct.JsSymbol.html" "JsSymbol"
 ///     click JsNull "./struct.JsNull.html" "JsNull"
 ///     click JsUndefined "./struct.JsUndefined.html" "JsUndefined"
 /// end
@@ -85,8 +87,8 @@
 ///
 /// The primitive types are the built-in JavaScript datatypes that are not object
 /// types: [`JsBoolean`](crate::types::JsBoolean), [`JsNumber`](crate::types::JsNumber),
-/// [`JsString`](crate::types::JsString), [`JsNull`](crate::types::JsNull), and
-/// [`JsUndefined`](crate::types::JsUndefined).
+/// [`JsString`](crate::types::JsString), [`JsSymbol`](crate::types::JsSymbol),
+/// [`JsNull`](crate::types::JsNull), and [`JsUndefined`](crate::types::JsUndefined).
 ///
 /// #### Object Types
 ///
@@ -101,22 +103,32 @@
 ///     JsArray(JsArray)
 ///     JsDate(JsDate)
 ///     JsError(JsError)
+///     JsMap(JsMap)
+///     JsRegExp(JsRegExp)
+///     JsSet(JsSet)
 ///     click JsFunction "./struct.JsFunction.html" "JsFunction"
 ///     click JsArray "./struct.JsArray.html" "JsArray"
 ///     click JsDate "./struct.JsDate.html" "JsDate"
 ///     click JsError "./struct.JsError.html" "JsError"
+///     click JsMap "./struct.JsMap.html" "JsMap"
+///     click JsRegExp "./struct.JsRegExp.html" "JsRegExp"
+///     click JsSet "./struct.JsSet.html" "JsSet"
 /// end
 /// subgraph typedarrays [Typed Arrays]
 ///     JsBuffer(JsBuffer)
 ///     JsArrayBuffer(JsArrayBuffer)
 ///     JsTypedArray("JsTypedArray&lt;T&gt;")
+///     JsDataView(JsDataView)
 ///     click JsBuffer "./struct.JsBuffer.html" "JsBuffer"
 ///     click JsArrayBuffer "./struct.JsArrayBuffer.html" "JsArrayBuffer"
 ///     click JsTypedArray "./struct.JsTypedArray.html" "JsTypedArray"
+///     click JsDataView "./struct.JsDataView.html" "JsDataView"
 /// end
 /// subgraph custom [Custom Types]
 ///     JsBox(JsBox)
+///     JsExternal(JsExternal)
 ///     click JsBox "./struct.JsBox.html" "JsBox"
+///     click JsExternal "./struct.JsExternal.html" "JsExternal"
 /// end
 /// JsObject-->objects
 /// JsObject-->typedarrays
@@ -125,13 +137,16 @@
 ///
 /// These include several categories of object types:
 /// - **Standard object types:** [`JsFunction`](crate::types::JsFunction),
-///   [`JsArray`](crate::types::JsArray), [`JsDate`](crate::types::JsDate), and
-///   [`JsError`](crate::types::JsError).
+///   [`JsArray`](crate::types::JsArray), [`JsDate`](crate::types::JsDate),
+///   [`JsError`](crate::types::JsError), [`JsMap`](crate::types::JsMap),
+///   [`JsRegExp`](crate::types::JsRegExp), and [`JsSet`](crate::types::JsSet).
 /// - **Typed arrays:** [`JsBuffer`](crate::types::JsBuffer),
-///   [`JsArrayBuffer`](crate::types::JsArrayBuffer), and
-///   [`JsTypedArray<T>`](crate::types::JsTypedArray).
+///   [`JsArrayBuffer`](crate::types::JsArrayBuffer),
+///   [`JsTypedArray<T>`](crate::types::JsTypedArray), and
+///   [`JsDataView`](crate::types::JsDataView).
 /// - **Custom types:** [`JsBox`](crate::types::JsBox), a special Neon type that allows
-///   the creation of custom objects that own Rust data structures.
+///   the creation of custom objects that own Rust data structures, and
+///   [`JsExternal`](crate::types::JsExternal), which holds a raw pointer.
 ///
 /// All object types implement the [`Object`](crate::object::Object) trait, which
 /// allows getting and setting properties of an object.
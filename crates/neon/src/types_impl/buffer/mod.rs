@@ -46,6 +46,36 @@ pub use types::Binary;
 /// }
 /// ```
 ///
+/// # Borrowing and JavaScript execution
+///
+/// Any call into JavaScript may detach a buffer or create another view of the same
+/// memory, so a borrowed slice must not outlive JavaScript execution. The statically
+/// checked borrows enforce this by borrowing the context for the lifetime of the
+/// slice: while a slice from [`TypedArray::as_mut_slice`] is alive, the context cannot
+/// be used to call a function or otherwise run JavaScript.
+///
+/// ```compile_fail,E0499
+/// # use neon::prelude::*;
+/// use neon::types::buffer::TypedArray;
+///
+/// fn unsound(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+///     let mut buf: Handle<JsBuffer> = cx.argument(0)?;
+///     let callback: Handle<JsFunction> = cx.argument(1)?;
+///     let data = buf.as_mut_slice(&mut cx);
+///
+///     // Error: `cx` is already borrowed by `data`
+///     callback.bind(&mut cx).exec()?;
+///
+///     data[0] = 0;
+///
+///     Ok(cx.undefined())
+/// }
+/// ```
+///
+/// To hold multiple borrows at once, use the dynamically checked
+/// [`TypedArray::try_borrow`] and [`TypedArray::try_borrow_mut`] with a
+/// [`Lock`](crate::context::Lock), which also borrows the context.
+///
 /// [typed-arrays]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Typed_arrays
 pub trait TypedArray: Value {
     type Item: Binary;
@@ -1,10 +1,10 @@
-use std::{marker::PhantomData, slice};
+use std::{marker::PhantomData, mem::MaybeUninit, ptr, slice};
 
 use crate::{
     context::{internal::Env, Context},
     handle::{internal::TransparentNoCopyWrapper, Handle},
     object::Object,
-    result::{JsResult, Throw},
+    result::{JsResult, NeonResult, Throw},
     sys::{self, raw, typedarray::TypedArrayInfo, TypedArrayType},
     types_impl::{
         buffer::{
@@ -78,6 +78,10 @@ impl JsBuffer {
     }
 
     /// Constructs a new `Buffer` object with uninitialized memory
+    ///
+    /// # Safety
+    ///
+    /// The contents of the buffer are uninitialized and must be written before being read.
     pub unsafe fn uninitialized<'a, C: Context<'a>>(cx: &mut C, len: usize) -> JsResult<'a, Self> {
         let result = sys::buffer::uninitialized(cx.env().to_raw(), len);
 
@@ -288,6 +292,45 @@ impl JsArrayBuffer {
         Handle::new_internal(Self(value))
     }
 
+    #[cfg(feature = "napi-7")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-7")))]
+    /// Detaches the buffer from its memory, as if it had been transferred with
+    /// `postMessage`. Afterward, the buffer and all views of it have a length of `0`.
+    ///
+    /// Throws a `TypeError` if the buffer is not detachable, e.g. if it is the memory of
+    /// a WebAssembly instance. Borrowed slices of the buffer borrow the context, so they
+    /// cannot be used after the buffer is detached.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// use neon::types::buffer::TypedArray;
+    ///
+    /// fn take(mut cx: FunctionContext) -> JsResult<JsBuffer> {
+    ///     let buf = cx.argument::<JsArrayBuffer>(0)?;
+    ///     let data = buf.as_slice(&cx).to_vec();
+    ///
+    ///     // The caller can no longer read or modify the data
+    ///     buf.detach(&mut cx)?;
+    ///
+    ///     JsBuffer::from_slice(&mut cx, &data)
+    /// }
+    /// ```
+    pub fn detach<'a, C: Context<'a>>(&self, cx: &mut C) -> NeonResult<()> {
+        if !unsafe { sys::arraybuffer::detach(cx.env().to_raw(), self.0) } {
+            return cx.throw_type_error("ArrayBuffer is not detachable");
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "napi-7")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-7")))]
+    /// Indicates whether the buffer has been detached, e.g. by [`JsArrayBuffer::detach`]
+    /// or by transferring it to a worker.
+    pub fn is_detached<'a, C: Context<'a>>(&self, cx: &mut C) -> bool {
+        unsafe { sys::arraybuffer::is_detached(cx.env().to_raw(), self.0) }
+    }
+
     /// Returns a region of this buffer.
     ///
     /// See also: [`Handle<JsArrayBuffer>::region()`](Handle::region) for a more
@@ -420,6 +463,179 @@ impl TypedArray for JsArrayBuffer {
     }
 }
 
+/// The type of JavaScript
+/// [`DataView`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView)
+/// objects.
+///
+/// A `DataView` reads and writes values of any [`Binary`] type at arbitrary byte
+/// offsets of an [`ArrayBuffer`](JsArrayBuffer), in either byte order. This is
+/// convenient for parsing binary formats that mix types and alignments.
+///
+/// # Example
+///
+/// ```
+/// # use neon::prelude::*;
+/// use neon::types::JsDataView;
+///
+/// // Reads a big-endian `u16` header followed by a little-endian `f64`
+/// fn read_record(mut cx: FunctionContext) -> JsResult<JsNumber> {
+///     let view = cx.argument::<JsDataView>(0)?;
+///     let header: u16 = view.get(&mut cx, 0, false)?;
+///     let value: f64 = view.get(&mut cx, 2, true)?;
+///
+///     Ok(cx.number(header as f64 + value))
+/// }
+/// ```
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct JsDataView(raw::Local);
+
+impl JsDataView {
+    /// Constructs a `DataView` of `len` bytes of `buffer`, starting at byte `offset`.
+    ///
+    /// Throws a `RangeError` if the view does not fit within the buffer.
+    pub fn new<'cx, C>(
+        cx: &mut C,
+        buffer: Handle<JsArrayBuffer>,
+        offset: usize,
+        len: usize,
+    ) -> JsResult<'cx, Self>
+    where
+        C: Context<'cx>,
+    {
+        let view = unsafe {
+            sys::dataview::new(cx.env().to_raw(), buffer.to_local(), offset, len)
+                .map_err(|_| Throw::new())?
+        };
+
+        Ok(Handle::new_internal(Self(view)))
+    }
+
+    /// Returns the `ArrayBuffer` viewed by this `DataView`.
+    pub fn buffer<'cx, C: Context<'cx>>(&self, cx: &mut C) -> Handle<'cx, JsArrayBuffer> {
+        let info = unsafe { sys::dataview::info(cx.env().to_raw(), self.to_local()) };
+
+        Handle::new_internal(unsafe { JsArrayBuffer::from_local(cx.env(), info.buf) })
+    }
+
+    /// Returns the offset in bytes of this view from the start of its `ArrayBuffer`.
+    pub fn byte_offset<'cx, C: Context<'cx>>(&self, cx: &mut C) -> usize {
+        unsafe { sys::dataview::info(cx.env().to_raw(), self.to_local()).offset }
+    }
+
+    /// Returns the length in bytes of this view.
+    pub fn byte_length<'cx, C: Context<'cx>>(&self, cx: &mut C) -> usize {
+        unsafe { sys::dataview::info(cx.env().to_raw(), self.to_local()).length }
+    }
+
+    /// Reads a value at byte `offset` of the view, like `DataView.prototype.getUint32`
+    /// and the other `DataView` getters. The value is read in little-endian byte order
+    /// if `little_endian` is `true`, and big-endian otherwise.
+    ///
+    /// Throws a `RangeError` if the value does not fit within the view.
+    pub fn get<'cx, T, C>(&self, cx: &mut C, offset: usize, little_endian: bool) -> NeonResult<T>
+    where
+        T: Binary,
+        C: Context<'cx>,
+    {
+        let size = std::mem::size_of::<T>();
+        let src = self.bytes(cx, offset, size)?;
+        let mut value = MaybeUninit::<T>::uninit();
+
+        // Safety: `src` is valid for `size` bytes and every bit pattern is a valid
+        // `Binary` value, since they are all integers or floats
+        unsafe {
+            let dst = value.as_mut_ptr().cast::<u8>();
+
+            ptr::copy_nonoverlapping(src, dst, size);
+
+            if little_endian != cfg!(target_endian = "little") {
+                slice::from_raw_parts_mut(dst, size).reverse();
+            }
+
+            Ok(value.assume_init())
+        }
+    }
+
+    /// Writes `value` at byte `offset` of the view, like `DataView.prototype.setUint32`
+    /// and the other `DataView` setters. The value is written in little-endian byte
+    /// order if `little_endian` is `true`, and big-endian otherwise.
+    ///
+    /// Throws a `RangeError` if the value does not fit within the view.
+    pub fn set<'cx, T, C>(
+        &self,
+        cx: &mut C,
+        offset: usize,
+        value: T,
+        little_endian: bool,
+    ) -> NeonResult<()>
+    where
+        T: Binary,
+        C: Context<'cx>,
+    {
+        let size = std::mem::size_of::<T>();
+        let dst = self.bytes(cx, offset, size)?;
+
+        // Safety: `dst` is valid for `size` bytes. Borrowing the context mutably
+        // prevents any other borrows of the buffer data.
+        unsafe {
+            ptr::copy_nonoverlapping((&value as *const T).cast::<u8>(), dst, size);
+
+            if little_endian != cfg!(target_endian = "little") {
+                slice::from_raw_parts_mut(dst, size).reverse();
+            }
+        }
+
+        Ok(())
+    }
+
+    // Returns a pointer to `len` bytes at `offset` of the view, throwing a `RangeError`
+    // if they are out of bounds
+    fn bytes<'cx, C: Context<'cx>>(
+        &self,
+        cx: &mut C,
+        offset: usize,
+        len: usize,
+    ) -> NeonResult<*mut u8> {
+        let info = unsafe { sys::dataview::info(cx.env().to_raw(), self.to_local()) };
+
+        match offset.checked_add(len) {
+            Some(end) if end <= info.length => Ok(unsafe { info.data.cast::<u8>().add(offset) }),
+            _ => cx.throw_range_error("Offset is outside the bounds of the DataView"),
+        }
+    }
+}
+
+unsafe impl TransparentNoCopyWrapper for JsDataView {
+    type Inner = raw::Local;
+
+    fn into_inner(self) -> Self::Inner {
+        self.0
+    }
+}
+
+impl ValueInternal for JsDataView {
+    fn name() -> &'static str {
+        "JsDataView"
+    }
+
+    fn is_typeof<Other: Value>(env: Env, other: &Other) -> bool {
+        unsafe { sys::tag::is_dataview(env.to_raw(), other.to_local()) }
+    }
+
+    fn to_local(&self) -> raw::Local {
+        self.0
+    }
+
+    unsafe fn from_local(_env: Env, h: raw::Local) -> Self {
+        Self(h)
+    }
+}
+
+impl Value for JsDataView {}
+
+impl Object for JsDataView {}
+
 /// A marker trait for all possible element types of binary buffers.
 ///
 /// This trait can only be implemented within the Neon library.
@@ -1,6 +1,7 @@
 use std::{
     error::Error,
     fmt::{self, Debug},
+    time::{Duration, SystemTime},
 };
 
 use super::{private::ValueInternal, Value};
@@ -101,6 +102,32 @@ impl DateErrorKind {
     }
 }
 
+/// An error produced when converting a `Date` with
+/// [`JsDate::to_system_time`](JsDate::to_system_time).
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-5")))]
+pub enum ToSystemTimeError {
+    /// The `Date` is invalid (`NaN`).
+    InvalidDate,
+    /// The `Date` is later than the latest `SystemTime` of the platform.
+    AfterMax,
+    /// The `Date` is earlier than the earliest `SystemTime` of the platform.
+    BeforeMin,
+}
+
+impl fmt::Display for ToSystemTimeError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str(match self {
+            ToSystemTimeError::InvalidDate => "Invalid Date",
+            ToSystemTimeError::AfterMax => "Date is later than the maximum SystemTime",
+            ToSystemTimeError::BeforeMin => "Date is earlier than the minimum SystemTime",
+        })
+    }
+}
+
+impl Error for ToSystemTimeError {}
+
 impl<'a, T: Value> ResultExt<Handle<'a, T>> for Result<Handle<'a, T>, DateError> {
     /// Creates an `Error` on error
     fn or_throw<'b, C: Context<'b>>(self, cx: &mut C) -> JsResult<'a, T> {
@@ -145,6 +172,43 @@ impl JsDate {
         Handle::new_internal(JsDate(local))
     }
 
+    /// Creates a new `Date` from a [`SystemTime`], truncated to millisecond precision.
+    /// It errors when `time` is outside the range of valid JavaScript `Date` values.
+    pub fn from_system_time<'a, C: Context<'a>>(
+        cx: &mut C,
+        time: SystemTime,
+    ) -> Result<Handle<'a, JsDate>, DateError> {
+        let millis = match time.duration_since(SystemTime::UNIX_EPOCH) {
+            Ok(duration) => duration.as_millis() as f64,
+            Err(err) => -(err.duration().as_millis() as f64),
+        };
+
+        JsDate::new(cx, millis)
+    }
+
+    /// Converts the `Date` to a [`SystemTime`]. It errors when the `Date` is invalid
+    /// or cannot be represented by the platform's `SystemTime`.
+    pub fn to_system_time<'a, C: Context<'a>>(
+        &self,
+        cx: &mut C,
+    ) -> Result<SystemTime, ToSystemTimeError> {
+        let value = self.value(cx);
+
+        if value.is_nan() {
+            return Err(ToSystemTimeError::InvalidDate);
+        }
+
+        if value >= 0.0 {
+            SystemTime::UNIX_EPOCH
+                .checked_add(Duration::from_millis(value as u64))
+                .ok_or(ToSystemTimeError::AfterMax)
+        } else {
+            SystemTime::UNIX_EPOCH
+                .checked_sub(Duration::from_millis(-value as u64))
+                .ok_or(ToSystemTimeError::BeforeMin)
+        }
+    }
+
     /// Gets the `Date`'s value. An invalid `Date` will return [`std::f64::NAN`].
     pub fn value<'a, C: Context<'a>>(&self, cx: &mut C) -> f64 {
         let env = cx.env().to_raw();
@@ -1,14 +1,19 @@
 //! Types and traits representing JavaScript error values.
 
-use std::panic::{catch_unwind, UnwindSafe};
+use std::{
+    panic::{catch_unwind, UnwindSafe},
+    ptr,
+};
 
 use crate::{
-    context::{internal::Env, Context},
+    context::{internal::Env, Context, Cx},
     handle::{internal::TransparentNoCopyWrapper, Handle},
-    object::Object,
-    result::{NeonResult, Throw},
+    object::{Object, PropertyKey},
+    result::{JsResult, NeonResult, Throw},
     sys::{self, raw},
-    types::{build, private::ValueInternal, utf8::Utf8, Value},
+    types::{
+        build, private::ValueInternal, utf8::Utf8, JsArray, JsFunction, JsString, JsValue, Value,
+    },
 };
 
 /// The type of JavaScript
@@ -74,11 +79,7 @@ impl JsError {
         cx: &mut C,
         msg: S,
     ) -> NeonResult<Handle<'a, JsError>> {
-        let msg = cx.string(msg.as_ref());
-        build(cx.env(), |out| unsafe {
-            sys::error::new_error(cx.env().to_raw(), out, msg.to_local());
-            true
-        })
+        Self::new(cx, ErrorKind::Error, None, msg.as_ref())
     }
 
     /// Creates an instance of the [`TypeError`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/TypeError) class.
@@ -88,11 +89,7 @@ impl JsError {
         cx: &mut C,
         msg: S,
     ) -> NeonResult<Handle<'a, JsError>> {
-        let msg = cx.string(msg.as_ref());
-        build(cx.env(), |out| unsafe {
-            sys::error::new_type_error(cx.env().to_raw(), out, msg.to_local());
-            true
-        })
+        Self::new(cx, ErrorKind::TypeError, None, msg.as_ref())
     }
 
     /// Creates an instance of the [`RangeError`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/RangeError) class.
@@ -102,14 +99,443 @@ impl JsError {
         cx: &mut C,
         msg: S,
     ) -> NeonResult<Handle<'a, JsError>> {
-        let msg = cx.string(msg.as_ref());
+        Self::new(cx, ErrorKind::RangeError, None, msg.as_ref())
+    }
+
+    /// Creates a direct instance of the [`Error`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/Error) class
+    /// with a `code` property.
+    ///
+    /// Errors can be passed to callbacks instead of thrown, in the error-first style of Node:
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn fail_with_callback(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    ///     let callback = cx.argument::<JsFunction>(0)?;
+    ///     let err = JsError::error_with_code(&mut cx, "ERR_CLOSED", "the connection is closed")?;
+    ///
+    ///     callback.bind(&mut cx).arg(err)?.exec()?;
+    ///
+    ///     Ok(cx.undefined())
+    /// }
+    /// ```
+    ///
+    /// **See also:** [`Context::throw_error_with_code`]
+    pub fn error_with_code<'a, C: Context<'a>, K: AsRef<str>, S: AsRef<str>>(
+        cx: &mut C,
+        code: K,
+        msg: S,
+    ) -> NeonResult<Handle<'a, JsError>> {
+        Self::new(cx, ErrorKind::Error, Some(code.as_ref()), msg.as_ref())
+    }
+
+    /// Creates an instance of the [`TypeError`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/TypeError) class
+    /// with a `code` property.
+    ///
+    /// **See also:** [`Context::throw_type_error_with_code`]
+    pub fn type_error_with_code<'a, C: Context<'a>, K: AsRef<str>, S: AsRef<str>>(
+        cx: &mut C,
+        code: K,
+        msg: S,
+    ) -> NeonResult<Handle<'a, JsError>> {
+        Self::new(cx, ErrorKind::TypeError, Some(code.as_ref()), msg.as_ref())
+    }
+
+    /// Creates an instance of the [`RangeError`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/RangeError) class
+    /// with a `code` property.
+    ///
+    /// **See also:** [`Context::throw_range_error_with_code`]
+    pub fn range_error_with_code<'a, C: Context<'a>, K: AsRef<str>, S: AsRef<str>>(
+        cx: &mut C,
+        code: K,
+        msg: S,
+    ) -> NeonResult<Handle<'a, JsError>> {
+        Self::new(cx, ErrorKind::RangeError, Some(code.as_ref()), msg.as_ref())
+    }
+
+    /// Creates an instance of the [`Error`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/Error) class
+    /// from a Rust error.
+    ///
+    /// The `message` is the [`Display`](std::fmt::Display) output of `err`. If `err` has a
+    /// [`source`](std::error::Error::source), it is converted recursively and set as the
+    /// `cause` property. If `err` is a [`std::io::Error`], the `code` and `errno`
+    /// properties are set as in [`ErrnoInfo`].
+    ///
+    /// **See also:** [`Context::throw_from_rust`]
+    pub fn from_rust<'a, C: Context<'a>>(
+        cx: &mut C,
+        err: &(dyn std::error::Error + 'static),
+    ) -> NeonResult<Handle<'a, JsError>> {
+        let info = err.downcast_ref::<std::io::Error>().map(ErrnoInfo::from);
+        let code = info.as_ref().and_then(|info| info.code.as_deref());
+        let error = Self::new(cx, ErrorKind::Error, code, &err.to_string())?;
+
+        if let Some(errno) = info.and_then(|info| info.errno) {
+            let errno = cx.number(errno);
+
+            set_property(cx, error, "errno", errno)?;
+        }
+
+        if let Some(source) = err.source() {
+            let cause = Self::from_rust(cx, source)?;
+
+            set_property(cx, error, "cause", cause)?;
+        }
+
+        Ok(error)
+    }
+
+    /// Creates an instance of the [`Error`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/Error) class
+    /// resembling a Node.js [`SystemError`](https://nodejs.org/api/errors.html#class-systemerror).
+    ///
+    /// The message is formatted like Node.js, e.g.
+    /// `"ENOENT: No such file or directory, open 'config.json'"`, and each field that is
+    /// `Some` is set as a property of the error.
+    ///
+    /// **See also:** [`Context::system_error`]
+    pub fn system_error<'a, C: Context<'a>>(
+        cx: &mut C,
+        info: ErrnoInfo,
+    ) -> NeonResult<Handle<'a, JsError>> {
+        let mut msg = String::new();
+
+        if let Some(code) = &info.code {
+            msg.push_str(code);
+            msg.push_str(": ");
+        }
+
+        msg.push_str(&info.message);
+
+        if let Some(syscall) = &info.syscall {
+            msg.push_str(", ");
+            msg.push_str(syscall);
+        }
+
+        if let Some(path) = &info.path {
+            msg.push_str(&format!(" '{path}'"));
+        }
+
+        let error = Self::new(cx, ErrorKind::Error, info.code.as_deref(), &msg)?;
+
+        if let Some(errno) = info.errno {
+            let errno = cx.number(errno);
+
+            set_property(cx, error, "errno", errno)?;
+        }
+
+        if let Some(syscall) = info.syscall {
+            let syscall = cx.string(syscall);
+
+            set_property(cx, error, "syscall", syscall)?;
+        }
+
+        if let Some(path) = info.path {
+            let path = cx.string(path);
+
+            set_property(cx, error, "path", path)?;
+        }
+
+        Ok(error)
+    }
+
+    /// Creates an instance of the [`AggregateError`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/AggregateError)
+    /// class, reporting several `errors` at once.
+    ///
+    /// Throws an `Error` if the JavaScript runtime does not support `AggregateError`,
+    /// e.g. Node.js versions older than 15.
+    ///
+    /// **See also:** [`Context::aggregate_error`]
+    pub fn aggregate_error<'cx, V: Value, S: AsRef<str>>(
+        cx: &mut Cx<'cx>,
+        errors: &[Handle<'cx, V>],
+        msg: S,
+    ) -> JsResult<'cx, JsError> {
+        let constructor: Option<Handle<JsFunction>> =
+            cx.global_object().prop(cx, "AggregateError").get()?;
+
+        let Some(constructor) = constructor else {
+            return cx.throw_error("AggregateError is not supported by this JavaScript runtime");
+        };
+
+        let errors = JsArray::from_slice(cx, errors)?;
+
+        constructor
+            .bind(cx)
+            .arg(errors)?
+            .arg(msg.as_ref())?
+            .construct()
+    }
+
+    // Creates an error of the given kind, optionally with a `code` property
+    pub(crate) fn new<'a, C: Context<'a>>(
+        cx: &mut C,
+        kind: ErrorKind,
+        code: Option<&str>,
+        msg: &str,
+    ) -> NeonResult<Handle<'a, JsError>> {
+        let code = code.map(|code| cx.string(code).to_local());
+        let msg = cx.string(msg);
+
         build(cx.env(), |out| unsafe {
-            sys::error::new_range_error(cx.env().to_raw(), out, msg.to_local());
+            let env = cx.env().to_raw();
+            let code = code.unwrap_or_else(ptr::null_mut);
+
+            match kind {
+                ErrorKind::Error => sys::error::new_error(env, out, code, msg.to_local()),
+                ErrorKind::TypeError => sys::error::new_type_error(env, out, code, msg.to_local()),
+                ErrorKind::RangeError => {
+                    sys::error::new_range_error(env, out, code, msg.to_local())
+                }
+            }
+
             true
         })
     }
 }
 
+impl JsError {
+    /// Reads the `message` property of the error, coercing it to a string.
+    pub fn message(&self, cx: &mut Cx) -> NeonResult<String> {
+        self.string_property(cx, "message")
+    }
+
+    /// Reads the `name` property of the error, e.g. `"TypeError"`, coercing it to a string.
+    pub fn name(&self, cx: &mut Cx) -> NeonResult<String> {
+        self.string_property(cx, "name")
+    }
+
+    /// Reads the `stack` property of the error, or `None` if it is not a string.
+    pub fn stack(&self, cx: &mut Cx) -> NeonResult<Option<String>> {
+        self.optional_string_property(cx, "stack")
+    }
+
+    /// Reads the `code` property of the error, or `None` if it is not a string.
+    pub fn code(&self, cx: &mut Cx) -> NeonResult<Option<String>> {
+        self.optional_string_property(cx, "code")
+    }
+
+    fn string_property(&self, cx: &mut Cx, key: &str) -> NeonResult<String> {
+        let value: Handle<JsValue> = self.prop(cx, key).get()?;
+
+        Ok(value.to_string(cx)?.value(cx))
+    }
+
+    fn optional_string_property(&self, cx: &mut Cx, key: &str) -> NeonResult<Option<String>> {
+        let value: Handle<JsValue> = self.prop(cx, key).get()?;
+
+        Ok(value
+            .downcast::<JsString, _>(cx)
+            .ok()
+            .map(|value| value.value(cx)))
+    }
+}
+
+// Sets a property on a newly created error
+fn set_property<'a, C: Context<'a>, V: Value>(
+    cx: &mut C,
+    error: Handle<'a, JsError>,
+    key: &str,
+    value: Handle<'a, V>,
+) -> NeonResult<()> {
+    let mut result = false;
+
+    // Safety: `error` and `value` are valid in the current scope of `cx`
+    unsafe {
+        if !key.set_from(cx, &mut result, error.to_local(), value.to_local()) {
+            return Err(Throw::new());
+        }
+    }
+
+    Ok(())
+}
+
+/// The properties of a Node.js [`SystemError`](https://nodejs.org/api/errors.html#class-systemerror),
+/// used to create an error with [`JsError::system_error`].
+///
+/// The conversion from [`std::io::Error`] fills in `message`, `errno` and `code`. The
+/// `syscall` and `path` can be added with struct update syntax:
+///
+/// ```
+/// # use neon::prelude::*;
+/// use neon::types::ErrnoInfo;
+///
+/// fn read_config(mut cx: FunctionContext) -> JsResult<JsString> {
+///     let path = cx.argument::<JsString>(0)?.value(&mut cx);
+///
+///     match std::fs::read_to_string(&path) {
+///         Ok(contents) => Ok(cx.string(contents)),
+///         Err(err) => {
+///             let err = cx.system_error(ErrnoInfo {
+///                 syscall: Some("open".into()),
+///                 path: Some(path),
+///                 ..err.into()
+///             })?;
+///
+///             cx.throw(err)
+///         }
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ErrnoInfo {
+    /// Description of the error
+    pub message: String,
+    /// The error number. Like Node.js, this is negative on Unix platforms.
+    pub errno: Option<i32>,
+    /// The string error code, e.g. `"ENOENT"`
+    pub code: Option<String>,
+    /// The name of the system call that failed, e.g. `"open"`
+    pub syscall: Option<String>,
+    /// The file system path related to the error
+    pub path: Option<String>,
+}
+
+impl From<std::io::Error> for ErrnoInfo {
+    fn from(err: std::io::Error) -> Self {
+        Self::from(&err)
+    }
+}
+
+impl From<&std::io::Error> for ErrnoInfo {
+    fn from(err: &std::io::Error) -> Self {
+        // libuv reports Unix error numbers as negative values
+        let errno = if cfg!(unix) {
+            err.raw_os_error().map(|errno| -errno)
+        } else {
+            err.raw_os_error()
+        };
+
+        // Prefer the name of the raw error number, so that `code` and `errno` agree
+        let code = match err.raw_os_error() {
+            #[cfg(unix)]
+            Some(errno) => errno_code(errno),
+            _ => io_error_code(err.kind()),
+        };
+
+        Self {
+            message: io_error_message(err),
+            errno,
+            code: code.map(String::from),
+            syscall: None,
+            path: None,
+        }
+    }
+}
+
+// Formats the message without the `(os error N)` suffix added by Rust
+fn io_error_message(err: &std::io::Error) -> String {
+    let message = err.to_string();
+
+    match err.raw_os_error() {
+        Some(errno) => message
+            .strip_suffix(&format!(" (os error {errno})"))
+            .map(String::from)
+            .unwrap_or(message),
+        None => message,
+    }
+}
+
+#[cfg(unix)]
+// Maps a Unix error number to its name, e.g. `ENOENT`
+fn errno_code(errno: i32) -> Option<&'static str> {
+    let code = match errno {
+        libc::EPERM => "EPERM",
+        libc::ENOENT => "ENOENT",
+        libc::ESRCH => "ESRCH",
+        libc::EINTR => "EINTR",
+        libc::EIO => "EIO",
+        libc::ENXIO => "ENXIO",
+        libc::E2BIG => "E2BIG",
+        libc::ENOEXEC => "ENOEXEC",
+        libc::EBADF => "EBADF",
+        libc::ECHILD => "ECHILD",
+        libc::EAGAIN => "EAGAIN",
+        libc::ENOMEM => "ENOMEM",
+        libc::EACCES => "EACCES",
+        libc::EFAULT => "EFAULT",
+        libc::EBUSY => "EBUSY",
+        libc::EEXIST => "EEXIST",
+        libc::EXDEV => "EXDEV",
+        libc::ENODEV => "ENODEV",
+        libc::ENOTDIR => "ENOTDIR",
+        libc::EISDIR => "EISDIR",
+        libc::EINVAL => "EINVAL",
+        libc::ENFILE => "ENFILE",
+        libc::EMFILE => "EMFILE",
+        libc::ENOTTY => "ENOTTY",
+        libc::ETXTBSY => "ETXTBSY",
+        libc::EFBIG => "EFBIG",
+        libc::ENOSPC => "ENOSPC",
+        libc::ESPIPE => "ESPIPE",
+        libc::EROFS => "EROFS",
+        libc::EMLINK => "EMLINK",
+        libc::EPIPE => "EPIPE",
+        libc::ERANGE => "ERANGE",
+        libc::ENAMETOOLONG => "ENAMETOOLONG",
+        libc::ENOSYS => "ENOSYS",
+        libc::ENOTEMPTY => "ENOTEMPTY",
+        libc::ELOOP => "ELOOP",
+        libc::ENOTSUP => "ENOTSUP",
+        libc::ENOTSOCK => "ENOTSOCK",
+        libc::EMSGSIZE => "EMSGSIZE",
+        libc::EPROTO => "EPROTO",
+        libc::EPROTONOSUPPORT => "EPROTONOSUPPORT",
+        libc::EAFNOSUPPORT => "EAFNOSUPPORT",
+        libc::EADDRINUSE => "EADDRINUSE",
+        libc::EADDRNOTAVAIL => "EADDRNOTAVAIL",
+        libc::ENETDOWN => "ENETDOWN",
+        libc::ENETUNREACH => "ENETUNREACH",
+        libc::ECONNABORTED => "ECONNABORTED",
+        libc::ECONNRESET => "ECONNRESET",
+        libc::ENOBUFS => "ENOBUFS",
+        libc::EISCONN => "EISCONN",
+        libc::ENOTCONN => "ENOTCONN",
+        libc::ETIMEDOUT => "ETIMEDOUT",
+        libc::ECONNREFUSED => "ECONNREFUSED",
+        libc::EHOSTUNREACH => "EHOSTUNREACH",
+        libc::EALREADY => "EALREADY",
+        libc::EINPROGRESS => "EINPROGRESS",
+        libc::ECANCELED => "ECANCELED",
+        _ => return None,
+    };
+
+    Some(code)
+}
+
+// Maps an error kind to the POSIX error code used by Node.js, if there is one. Used when
+// there is no raw error number.
+fn io_error_code(kind: std::io::ErrorKind) -> Option<&'static str> {
+    use std::io::ErrorKind;
+
+    let code = match kind {
+        ErrorKind::NotFound => "ENOENT",
+        ErrorKind::PermissionDenied => "EACCES",
+        ErrorKind::ConnectionRefused => "ECONNREFUSED",
+        ErrorKind::ConnectionReset => "ECONNRESET",
+        ErrorKind::ConnectionAborted => "ECONNABORTED",
+        ErrorKind::NotConnected => "ENOTCONN",
+        ErrorKind::AddrInUse => "EADDRINUSE",
+        ErrorKind::AddrNotAvailable => "EADDRNOTAVAIL",
+        ErrorKind::BrokenPipe => "EPIPE",
+        ErrorKind::AlreadyExists => "EEXIST",
+        ErrorKind::WouldBlock => "EAGAIN",
+        ErrorKind::InvalidInput => "EINVAL",
+        ErrorKind::TimedOut => "ETIMEDOUT",
+        ErrorKind::Interrupted => "EINTR",
+        ErrorKind::Unsupported => "ENOTSUP",
+        ErrorKind::OutOfMemory => "ENOMEM",
+        _ => return None,
+    };
+
+    Some(code)
+}
+
+#[derive(Clone, Copy)]
+pub(crate) enum ErrorKind {
+    Error,
+    TypeError,
+    RangeError,
+}
+
 pub(crate) fn convert_panics<T, F: UnwindSafe + FnOnce() -> NeonResult<T>>(
     env: Env,
     f: F,
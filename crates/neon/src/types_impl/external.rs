@@ -0,0 +1,169 @@
+use std::ffi::c_void;
+
+use super::{private::ValueInternal, Value};
+
+use crate::{
+    context::{internal::Env, Context},
+    handle::{internal::TransparentNoCopyWrapper, Handle},
+    sys::{self, raw},
+};
+
+/// The type of JavaScript values that hold a raw pointer, e.g. to data owned by
+/// another native library.
+///
+/// Unlike [`JsBox`](crate::types::JsBox), a `JsExternal` does not own Rust data.
+/// Instead, a finalizer is called with the pointer when the value is garbage collected.
+/// JavaScript can pass any external back to Rust, including externals created by other
+/// native modules, so pointers should be verified with a type tag before they are
+/// dereferenced. A [`JsBox`](crate::types::JsBox) created by this module is not a
+/// `JsExternal`.
+///
+/// `JsExternal` requires the `napi-8` feature. Neon relies on type tags to tell a
+/// [`JsBox`](crate::types::JsBox) apart from other externals, which are not available
+/// with earlier versions of Node-API.
+///
+/// # Example
+///
+/// ```
+/// # use neon::prelude::*;
+/// use neon::types::{ExternalTag, JsExternal};
+///
+/// // A handle allocated by a C library
+/// struct Database;
+///
+/// impl ExternalTag for Database {
+///     const TYPE_TAG: u128 = 0x1d8b_67a3_9a5c_4cd1_8f09_1c57_e2d0_3b64;
+/// }
+///
+/// fn close(db: *mut Database) {
+///     // Call the C library to free `db`
+/// }
+///
+/// fn open(mut cx: FunctionContext) -> JsResult<JsExternal> {
+///     // Obtained from the C library
+///     let db: *mut Database = std::ptr::null_mut();
+///
+///     Ok(JsExternal::tagged(&mut cx, db, close))
+/// }
+///
+/// fn query(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+///     let external = cx.argument::<JsExternal>(0)?;
+///
+///     let Some(db) = external.tagged_pointer::<Database, _>(&mut cx) else {
+///         return cx.throw_type_error("expected a database");
+///     };
+///
+///     // `db` was created by this module and is a `*mut Database`
+///     # let _ = db;
+///
+///     Ok(cx.undefined())
+/// }
+/// ```
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct JsExternal(raw::Local);
+
+impl JsExternal {
+    /// Creates an external holding `ptr`. The `finalizer` is called with `ptr` when the
+    /// external is garbage collected.
+    ///
+    /// **See also:** [`Context::external`]
+    // The pointer is never dereferenced by Neon, only stored and passed to `finalizer`
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn new<'a, C: Context<'a>, T>(
+        cx: &mut C,
+        ptr: *mut T,
+        finalizer: fn(*mut T),
+    ) -> Handle<'a, JsExternal> {
+        let local = unsafe { sys::external::create_pointer(cx.env().to_raw(), ptr, finalizer) };
+
+        Handle::new_internal(JsExternal(local))
+    }
+
+    /// Returns the pointer held by the external.
+    ///
+    /// The pointer is not checked and may have been created by any native module. Prefer
+    /// [`JsExternal::tagged_pointer`].
+    pub fn pointer<'a, C: Context<'a>>(&self, cx: &mut C) -> *mut c_void {
+        unsafe { sys::external::pointer(cx.env().to_raw(), self.0) }
+    }
+
+    /// Creates an external holding `ptr`, branded with the [`ExternalTag`] of `T`. The
+    /// `finalizer` is called with `ptr` when the external is garbage collected.
+    pub fn tagged<'a, C: Context<'a>, T: ExternalTag>(
+        cx: &mut C,
+        ptr: *mut T,
+        finalizer: fn(*mut T),
+    ) -> Handle<'a, JsExternal> {
+        let external = Self::new(cx, ptr, finalizer);
+        let tag = type_tag::<T>();
+
+        unsafe { sys::tag::type_tag_object(cx.env().to_raw(), external.to_local(), &tag) };
+
+        external
+    }
+
+    /// Returns the pointer held by the external if it was created with
+    /// [`JsExternal::tagged`] for the same type `T`, or `None` otherwise.
+    pub fn tagged_pointer<'a, T: ExternalTag, C: Context<'a>>(&self, cx: &mut C) -> Option<*mut T> {
+        let env = cx.env().to_raw();
+        let tag = type_tag::<T>();
+
+        if !unsafe { sys::tag::check_object_type_tag(env, self.0, &tag) } {
+            return None;
+        }
+
+        Some(self.pointer(cx).cast())
+    }
+}
+
+/// A unique tag identifying the type of the pointer held by a [`JsExternal`].
+///
+/// The tag is a 128-bit value, usually a randomly generated UUID, and must be unique
+/// across all native modules that may share externals.
+pub trait ExternalTag {
+    /// The type tag
+    const TYPE_TAG: u128;
+}
+
+fn type_tag<T: ExternalTag>() -> sys::TypeTag {
+    sys::TypeTag {
+        lower: T::TYPE_TAG as u64,
+        upper: (T::TYPE_TAG >> 64) as u64,
+    }
+}
+
+impl Value for JsExternal {}
+
+unsafe impl TransparentNoCopyWrapper for JsExternal {
+    type Inner = raw::Local;
+
+    fn into_inner(self) -> Self::Inner {
+        self.0
+    }
+}
+
+impl ValueInternal for JsExternal {
+    fn name() -> &'static str {
+        "external"
+    }
+
+    fn is_typeof<Other: Value>(env: Env, other: &Other) -> bool {
+        let env = env.to_raw();
+        let local = other.to_local();
+
+        // A `JsBox` is also an external, but its pointer is owned by Neon
+        unsafe {
+            sys::tag::is_external(env, local)
+                && !sys::tag::check_object_type_tag(env, local, &crate::MODULE_TAG)
+        }
+    }
+
+    fn to_local(&self) -> raw::Local {
+        self.0
+    }
+
+    unsafe fn from_local(_env: Env, h: raw::Local) -> Self {
+        JsExternal(h)
+    }
+}
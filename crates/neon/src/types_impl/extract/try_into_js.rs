@@ -128,7 +128,7 @@ impl<'cx> TryIntoJs<'cx> for String {
     }
 }
 
-impl<'a, 'cx> TryIntoJs<'cx> for &'a str {
+impl<'cx> TryIntoJs<'cx> for &str {
     type Value = JsString;
 
     fn try_into_js(self, cx: &mut Cx<'cx>) -> JsResult<'cx, Self::Value> {
@@ -136,7 +136,7 @@ impl<'a, 'cx> TryIntoJs<'cx> for &'a str {
     }
 }
 
-impl<'a, 'cx> TryIntoJs<'cx> for &'a String {
+impl<'cx> TryIntoJs<'cx> for &String {
     type Value = JsString;
 
     fn try_into_js(self, cx: &mut Cx<'cx>) -> JsResult<'cx, Self::Value> {
@@ -180,7 +180,7 @@ where
     }
 }
 
-impl<'a, 'cx, T> TryIntoJs<'cx> for &'a Vec<T>
+impl<'cx, T> TryIntoJs<'cx> for &Vec<T>
 where
     JsTypedArray<T>: Value,
     T: Binary,
@@ -192,7 +192,7 @@ where
     }
 }
 
-impl<'a, 'cx, T> TryIntoJs<'cx> for &'a [T]
+impl<'cx, T> TryIntoJs<'cx> for &[T]
 where
     JsTypedArray<T>: Value,
     T: Binary,
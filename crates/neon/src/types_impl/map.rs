@@ -0,0 +1,129 @@
+use super::{private::ValueInternal, Value};
+
+use crate::{
+    context::{internal::Env, Context, Cx},
+    handle::{internal::TransparentNoCopyWrapper, Handle},
+    object::Object,
+    result::{JsResult, NeonResult},
+    sys::{self, raw},
+    types::{
+        extract::{TryFromJs, TryIntoJs},
+        JsFunction,
+    },
+};
+
+/// The type of JavaScript
+/// [`Map`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map)
+/// objects.
+///
+/// Methods call the `Map.prototype` methods of the object, so subclasses and
+/// monkey-patched prototypes are respected.
+///
+/// # Example
+///
+/// ```
+/// # use neon::prelude::*;
+/// use neon::types::JsMap;
+///
+/// fn count_words(mut cx: FunctionContext) -> JsResult<JsMap> {
+///     let text = cx.argument::<JsString>(0)?.value(&mut cx);
+///     let counts = JsMap::new(&mut cx)?;
+///
+///     for word in text.split_whitespace() {
+///         let count: Option<f64> = counts.get(&mut cx, word)?;
+///
+///         counts.set(&mut cx, word, count.unwrap_or(0.0) + 1.0)?;
+///     }
+///
+///     Ok(counts)
+/// }
+/// ```
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct JsMap(raw::Local);
+
+impl JsMap {
+    /// Creates a new empty `Map` with the global `Map` constructor.
+    pub fn new<'cx>(cx: &mut Cx<'cx>) -> JsResult<'cx, JsMap> {
+        let map: Handle<JsFunction> = cx.global("Map")?;
+
+        map.bind(cx).construct()
+    }
+
+    /// Returns the number of entries in the `Map`.
+    pub fn size(&self, cx: &mut Cx) -> NeonResult<usize> {
+        let size: f64 = self.prop(cx, "size").get()?;
+
+        Ok(size as usize)
+    }
+
+    /// Gets the value associated with `key`, converted to `R`. A missing key produces
+    /// `undefined`, which can be extracted as an `Option`.
+    pub fn get<'cx, K, R>(&self, cx: &mut Cx<'cx>, key: K) -> NeonResult<R>
+    where
+        K: TryIntoJs<'cx>,
+        R: TryFromJs<'cx>,
+    {
+        self.method(cx, "get")?.arg(key)?.call()
+    }
+
+    /// Associates `value` with `key`, replacing any existing value.
+    pub fn set<'cx, K, V>(&self, cx: &mut Cx<'cx>, key: K, value: V) -> NeonResult<()>
+    where
+        K: TryIntoJs<'cx>,
+        V: TryIntoJs<'cx>,
+    {
+        self.method(cx, "set")?.arg(key)?.arg(value)?.exec()
+    }
+
+    /// Checks if the `Map` contains `key`.
+    pub fn has<'cx, K>(&self, cx: &mut Cx<'cx>, key: K) -> NeonResult<bool>
+    where
+        K: TryIntoJs<'cx>,
+    {
+        self.method(cx, "has")?.arg(key)?.call()
+    }
+
+    /// Removes `key` from the `Map`. Returns `true` if the key was present.
+    pub fn delete<'cx, K>(&self, cx: &mut Cx<'cx>, key: K) -> NeonResult<bool>
+    where
+        K: TryIntoJs<'cx>,
+    {
+        self.method(cx, "delete")?.arg(key)?.call()
+    }
+
+    /// Removes all entries from the `Map`.
+    pub fn clear(&self, cx: &mut Cx) -> NeonResult<()> {
+        self.method(cx, "clear")?.exec()
+    }
+}
+
+impl Value for JsMap {}
+
+unsafe impl TransparentNoCopyWrapper for JsMap {
+    type Inner = raw::Local;
+
+    fn into_inner(self) -> Self::Inner {
+        self.0
+    }
+}
+
+impl ValueInternal for JsMap {
+    fn name() -> &'static str {
+        "Map"
+    }
+
+    fn is_typeof<Other: Value>(env: Env, other: &Other) -> bool {
+        unsafe { sys::tag::is_instance_of_global(env.to_raw(), other.to_local(), "Map") }
+    }
+
+    fn to_local(&self) -> raw::Local {
+        self.0
+    }
+
+    unsafe fn from_local(_env: Env, h: raw::Local) -> Self {
+        JsMap(h)
+    }
+}
+
+impl Object for JsMap {}
@@ -8,9 +8,15 @@ pub mod buffer;
 #[cfg(feature = "napi-5")]
 pub(crate) mod date;
 pub(crate) mod error;
+#[cfg(feature = "napi-8")]
+pub(crate) mod external;
 pub mod extract;
 pub mod function;
+pub(crate) mod map;
 pub(crate) mod promise;
+pub(crate) mod regexp;
+pub(crate) mod set;
+pub(crate) mod symbol;
 
 pub(crate) mod private;
 pub(crate) mod utf8;
@@ -42,21 +48,47 @@ use crate::{
 pub use self::{
     boxed::{Finalize, JsBox},
     buffer::types::{
-        JsArrayBuffer, JsBigInt64Array, JsBigUint64Array, JsBuffer, JsFloat32Array, JsFloat64Array,
-        JsInt16Array, JsInt32Array, JsInt8Array, JsTypedArray, JsUint16Array, JsUint32Array,
-        JsUint8Array,
+        JsArrayBuffer, JsBigInt64Array, JsBigUint64Array, JsBuffer, JsDataView, JsFloat32Array,
+        JsFloat64Array, JsInt16Array, JsInt32Array, JsInt8Array, JsTypedArray, JsUint16Array,
+        JsUint32Array, JsUint8Array,
     },
-    error::JsError,
+    error::{ErrnoInfo, JsError},
+    map::JsMap,
     promise::{Deferred, JsPromise},
+    regexp::JsRegExp,
+    set::JsSet,
+    symbol::JsSymbol,
 };
 
 #[cfg(feature = "napi-5")]
-pub use self::date::{DateError, DateErrorKind, JsDate};
+pub use self::date::{DateError, DateErrorKind, JsDate, ToSystemTimeError};
+
+#[cfg(feature = "napi-8")]
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-8")))]
+pub use self::external::{ExternalTag, JsExternal};
 
 #[cfg(all(feature = "napi-5", feature = "futures"))]
 #[cfg_attr(docsrs, doc(cfg(all(feature = "napi-5", feature = "futures"))))]
 pub use self::promise::JsFuture;
 
+/// The type of a JavaScript value, as returned by [`Handle::type_of`].
+///
+/// These mirror the results of the JavaScript `typeof` operator, except that `null`
+/// is distinguished from objects and [`JsBox`] values are `External`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ValueType {
+    Undefined,
+    Null,
+    Boolean,
+    Number,
+    String,
+    Symbol,
+    Object,
+    Function,
+    External,
+    BigInt,
+}
+
 // This should be considered deprecated and will be removed:
 // https://github.com/neon-bindings/neon/issues/983
 pub(crate) fn build<'a, T: Value, F: FnOnce(&mut raw::Local) -> bool>(
@@ -87,6 +119,21 @@ impl<T: Object> SuperType<T> for JsObject {
 
 /// The trait shared by all JavaScript values.
 pub trait Value: ValueInternal {
+    /// Coerces the value to a string with the JavaScript rules of a template literal,
+    /// e.g. `` `${value}` ``.
+    ///
+    /// Exceptions thrown during coercion are propagated, including a `TypeError` when
+    /// coercing a `Symbol` and any exception thrown by a `toString` method.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn describe(mut cx: FunctionContext) -> JsResult<JsString> {
+    ///     let arg = cx.argument::<JsValue>(0)?;
+    ///     let s = arg.to_string(&mut cx)?.value(&mut cx);
+    ///
+    ///     Ok(cx.string(format!("value: {s}")))
+    /// }
+    /// ```
     fn to_string<'cx, C: Context<'cx>>(&self, cx: &mut C) -> JsResult<'cx, JsString> {
         let env = cx.env();
         build(env, |out| unsafe {
@@ -610,6 +657,24 @@ impl JsString {
         }
     }
 
+    /// Convert this JavaScript string into a [`Vec<u8>`] encoded as
+    /// [Latin-1](https://en.wikipedia.org/wiki/ISO/IEC_8859-1).
+    ///
+    /// Each UTF-16 code unit is truncated to its low byte, so the conversion is lossy
+    /// for characters outside of Latin-1. It is faster than [`JsString::value`] for
+    /// strings known to be ASCII.
+    pub fn to_latin1<'a, C: Context<'a>>(&self, cx: &mut C) -> Vec<u8> {
+        let env = cx.env().to_raw();
+
+        unsafe {
+            let capacity = sys::string::utf16_len(env, self.to_local()) + 1;
+            let mut buffer: Vec<u8> = Vec::with_capacity(capacity);
+            let len = sys::string::data_latin1(env, buffer.as_mut_ptr(), capacity, self.to_local());
+            buffer.set_len(len);
+            buffer
+        }
+    }
+
     /// Creates a new `JsString` value from a Rust string by copying its contents.
     ///
     /// This method panics if the string is longer than the maximum string size allowed
@@ -661,6 +726,69 @@ impl JsString {
         }
     }
 
+    /// Creates a new `JsString` value from UTF-16 code units by copying them. Unpaired
+    /// surrogates are copied as-is, matching JavaScript string semantics.
+    ///
+    /// This method panics if the string is longer than the maximum string size allowed
+    /// by the JavaScript engine.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// # fn string_from_utf16(mut cx: FunctionContext) -> JsResult<JsString> {
+    /// let units: Vec<u16> = "hello 🥹".encode_utf16().collect();
+    /// let str = JsString::from_utf16(&mut cx, &units);
+    /// assert_eq!("hello 🥹", str.value(&mut cx));
+    /// # Ok(str)
+    /// # }
+    /// ```
+    pub fn from_utf16<'a, C: Context<'a>>(cx: &mut C, val: &[u16]) -> Handle<'a, JsString> {
+        JsString::try_from_utf16(cx, val).unwrap()
+    }
+
+    /// Tries to create a new `JsString` value from UTF-16 code units by copying them.
+    ///
+    /// Returns `Err(StringOverflow)` if the string is longer than the maximum string size
+    /// allowed by the JavaScript engine.
+    pub fn try_from_utf16<'a, C: Context<'a>>(cx: &mut C, val: &[u16]) -> StringResult<'a> {
+        let env = cx.env().to_raw();
+
+        unsafe {
+            let mut local: raw::Local = std::mem::zeroed();
+            if sys::string::new_utf16(&mut local, env, val.as_ptr(), val.len()) {
+                Ok(Handle::new_internal(JsString(local)))
+            } else {
+                Err(StringOverflow(val.len()))
+            }
+        }
+    }
+
+    /// Creates a new `JsString` value from
+    /// [Latin-1](https://en.wikipedia.org/wiki/ISO/IEC_8859-1) encoded bytes by copying
+    /// them. Every byte is a valid Latin-1 character.
+    ///
+    /// This method panics if the string is longer than the maximum string size allowed
+    /// by the JavaScript engine.
+    pub fn from_latin1<'a, C: Context<'a>>(cx: &mut C, val: &[u8]) -> Handle<'a, JsString> {
+        JsString::try_from_latin1(cx, val).unwrap()
+    }
+
+    /// Tries to create a new `JsString` value from Latin-1 encoded bytes by copying them.
+    ///
+    /// Returns `Err(StringOverflow)` if the string is longer than the maximum string size
+    /// allowed by the JavaScript engine.
+    pub fn try_from_latin1<'a, C: Context<'a>>(cx: &mut C, val: &[u8]) -> StringResult<'a> {
+        let env = cx.env().to_raw();
+
+        unsafe {
+            let mut local: raw::Local = std::mem::zeroed();
+            if sys::string::new_latin1(&mut local, env, val.as_ptr(), val.len()) {
+                Ok(Handle::new_internal(JsString(local)))
+            } else {
+                Err(StringOverflow(val.len()))
+            }
+        }
+    }
+
     pub(crate) fn new_internal<'a>(env: Env, val: &str) -> Option<Handle<'a, JsString>> {
         let (ptr, len) = if let Some(small) = Utf8::from(val).into_small() {
             small.lower()
@@ -721,6 +849,67 @@ impl JsNumber {
         let env = cx.env().to_raw();
         unsafe { sys::primitive::number_value(env, self.to_local()) }
     }
+
+    /// Returns the value of this number as a Rust `i32`.
+    ///
+    /// Unlike casting the result of [`JsNumber::value`], this throws a `RangeError` if
+    /// the number is not an integer or does not fit in an `i32`, e.g.,
+    /// `"expected a 32-bit integer, got 3000000000.5"`.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn get_user(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    ///     let id = cx.argument::<JsNumber>(0)?.as_i32(&mut cx)?;
+    ///
+    ///     Ok(cx.number(id))
+    /// }
+    /// ```
+    pub fn as_i32<'a, C: Context<'a>>(&self, cx: &mut C) -> NeonResult<i32> {
+        self.integer(cx, i32::MIN.into(), i32::MAX.into(), "a 32-bit integer")
+            .map(|n| n as i32)
+    }
+
+    /// Returns the value of this number as a Rust `u32`, throwing a `RangeError` if the
+    /// number is not an integer or does not fit in a `u32`.
+    pub fn as_u32<'a, C: Context<'a>>(&self, cx: &mut C) -> NeonResult<u32> {
+        self.integer(cx, 0.0, u32::MAX.into(), "an unsigned 32-bit integer")
+            .map(|n| n as u32)
+    }
+
+    /// Returns the value of this number as a Rust `i64`, throwing a `RangeError` if the
+    /// number is not a [safe integer](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/Number/isSafeInteger).
+    ///
+    /// Numbers outside of the safe range may already have lost precision, so they are
+    /// rejected even if they fit in an `i64`. Use [`JsBigInt`](crate::types::JsBigInt) for
+    /// larger integers.
+    pub fn as_i64<'a, C: Context<'a>>(&self, cx: &mut C) -> NeonResult<i64> {
+        // `Number.MAX_SAFE_INTEGER`
+        const MAX_SAFE_INTEGER: f64 = 9_007_199_254_740_991.0;
+
+        self.integer(cx, -MAX_SAFE_INTEGER, MAX_SAFE_INTEGER, "a safe integer")
+            .map(|n| n as i64)
+    }
+
+    // Returns the value of this number if it is an integer in `min..=max`
+    fn integer<'a, C: Context<'a>>(
+        &self,
+        cx: &mut C,
+        min: f64,
+        max: f64,
+        expected: &str,
+    ) -> NeonResult<f64> {
+        let n = self.value(cx);
+
+        // `NaN` fails both comparisons
+        if n.fract() == 0.0 && n >= min && n <= max {
+            return Ok(n);
+        }
+
+        // Format the number the same as JavaScript, e.g. `Infinity` instead of `inf`
+        let got = self.to_string(cx)?.value(cx);
+
+        cx.throw_range_error(format!("expected {expected}, got {got}"))
+    }
 }
 
 impl Value for JsNumber {}
@@ -897,6 +1086,86 @@ impl JsArray {
         }
     }
 
+    /// Converts each element of the array into a Rust value with
+    /// [`TryFromJs`](extract::TryFromJs), collecting the results into a [`Vec`].
+    ///
+    /// If an element cannot be converted, its error is thrown with the index of the
+    /// element prepended to the `message`.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn sum(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    ///     let nums = cx.argument::<JsArray>(0)?.to_vec_of::<f64>(&mut cx)?;
+    ///
+    ///     Ok(cx.number(nums.into_iter().sum::<f64>()))
+    /// }
+    /// ```
+    pub fn to_vec_of<'cx, T>(&self, cx: &mut Cx<'cx>) -> NeonResult<Vec<T>>
+    where
+        T: extract::TryFromJs<'cx>,
+    {
+        let mut result = Vec::with_capacity(self.len(cx) as usize);
+        let mut i = 0;
+
+        // Like `to_vec`, the length is re-checked since conversion may run arbitrary code
+        while i < self.len(cx) {
+            let v = self.get(cx, i)?;
+
+            match T::try_from_js(cx, v)? {
+                Ok(v) => result.push(v),
+                Err(err) => {
+                    let err = extract::TryIntoJs::try_into_js(err, cx)?.upcast::<JsValue>();
+
+                    if let Ok(obj) = err.downcast::<JsObject, _>(cx) {
+                        if let Some(message) = obj.prop(cx, "message").get::<Option<String>>()? {
+                            obj.prop(cx, "message")
+                                .set(format!("element {i}: {message}"))?;
+                        }
+                    }
+
+                    return cx.throw(err);
+                }
+            }
+
+            i += 1;
+        }
+
+        Ok(result)
+    }
+
+    /// Creates a new array containing the values of a slice, in order.
+    pub fn from_slice<'a, C: Context<'a>, V: Value>(
+        cx: &mut C,
+        values: &[Handle<V>],
+    ) -> JsResult<'a, JsArray> {
+        Self::from_iter(cx, values.iter().copied())
+    }
+
+    /// Creates a new array from an iterator of values, in order.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn greetings(mut cx: FunctionContext) -> JsResult<JsArray> {
+    ///     let names = ["hello", "world"].map(|name| cx.string(name));
+    ///
+    ///     JsArray::from_iter(&mut cx, names)
+    /// }
+    /// ```
+    pub fn from_iter<'a, 'b, C, V, I>(cx: &mut C, values: I) -> JsResult<'a, JsArray>
+    where
+        C: Context<'a>,
+        V: Value,
+        I: IntoIterator<Item = Handle<'b, V>>,
+    {
+        let array = cx.empty_array();
+
+        for (i, v) in values.into_iter().enumerate() {
+            array.set(cx, i as u32, v)?;
+        }
+
+        Ok(array)
+    }
+
     fn len_inner(&self, env: Env) -> u32 {
         unsafe { sys::array::len(env.to_raw(), self.to_local()) }
     }
@@ -913,6 +1182,60 @@ impl JsArray {
     pub fn is_empty<'a, C: Context<'a>>(&self, cx: &mut C) -> bool {
         self.len(cx) == 0
     }
+
+    /// Appends a value to the end of the array, returning the new length. Equivalent
+    /// to the JavaScript expression
+    /// [`this.push(value)`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/push).
+    pub fn push<'cx, V: extract::TryIntoJs<'cx>>(
+        &self,
+        cx: &mut Cx<'cx>,
+        value: V,
+    ) -> NeonResult<u32> {
+        let len: f64 = self.method(cx, "push")?.arg(value)?.call()?;
+
+        Ok(len as u32)
+    }
+
+    /// Removes the last element of the array and returns it, or `undefined` if the array
+    /// is empty. Equivalent to the JavaScript expression
+    /// [`this.pop()`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/pop).
+    pub fn pop<'cx>(&self, cx: &mut Cx<'cx>) -> JsResult<'cx, JsValue> {
+        self.method(cx, "pop")?.call()
+    }
+
+    /// Removes `delete_count` elements starting at `start` and inserts `items` in their
+    /// place, returning an array of the removed elements. Equivalent to the JavaScript
+    /// expression
+    /// [`this.splice(start, deleteCount, ...items)`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/splice).
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn replace_first(mut cx: FunctionContext) -> JsResult<JsArray> {
+    ///     let array = cx.argument::<JsArray>(0)?;
+    ///     let value = cx.argument::<JsValue>(1)?;
+    ///
+    ///     array.splice(&mut cx, 0, 1, &[value])?;
+    ///
+    ///     Ok(array)
+    /// }
+    /// ```
+    pub fn splice<'cx>(
+        &self,
+        cx: &mut Cx<'cx>,
+        start: u32,
+        delete_count: u32,
+        items: &[Handle<'cx, JsValue>],
+    ) -> JsResult<'cx, JsArray> {
+        let mut splice = self.method(cx, "splice")?;
+
+        splice.arg(start)?.arg(delete_count)?;
+
+        for &item in items {
+            splice.arg(item)?;
+        }
+
+        splice.call()
+    }
 }
 
 impl Value for JsArray {}
@@ -1193,6 +1516,77 @@ impl JsFunction {
             args: smallvec![],
         }
     }
+
+    /// Creates a new function with `this` and leading arguments fixed, like
+    /// [`Function.prototype.bind`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/Function/bind)
+    /// in JavaScript.
+    ///
+    /// Calling the bound function passes `args` followed by any arguments it is called
+    /// with. As in JavaScript, the `length` of the bound function is the `length` of this
+    /// function less the number of bound arguments, but not less than zero.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn on_data(mut cx: FunctionContext) -> JsResult<JsFunction> {
+    ///     let emitter = cx.argument::<JsObject>(0)?;
+    ///     let listener = cx.argument::<JsFunction>(1)?;
+    ///     let source = cx.string("stdin").upcast();
+    ///
+    ///     // `listener` is called as `listener.call(emitter, "stdin", ...data)`
+    ///     listener.bind_args(&mut cx, emitter, &[source])
+    /// }
+    /// ```
+    pub fn bind_args<'cx, T: Value>(
+        &self,
+        cx: &mut Cx<'cx>,
+        this: Handle<'cx, T>,
+        args: &[Handle<'cx, JsValue>],
+    ) -> JsResult<'cx, JsFunction> {
+        let mut bind = self.method(cx, "bind")?;
+
+        bind.arg(this)?;
+
+        for &arg in args {
+            bind.arg(arg)?;
+        }
+
+        bind.call()
+    }
+
+    /// Returns the number of parameters declared by the function, from its
+    /// [`length`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/Function/length)
+    /// property. Rest parameters and parameters with defaults are not counted.
+    ///
+    /// A redefined `length` is coerced to a number and clamped to the range of a `u32`.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn notify(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    ///     let callback = cx.argument::<JsFunction>(0)?;
+    ///
+    ///     // Node.js style callbacks take an error as the first argument
+    ///     if callback.arity(&mut cx)? >= 2 {
+    ///         let err = cx.null();
+    ///
+    ///         callback.bind(&mut cx).arg(err)?.arg("done")?.exec()?;
+    ///     } else {
+    ///         callback.bind(&mut cx).arg("done")?.exec()?;
+    ///     }
+    ///
+    ///     Ok(cx.undefined())
+    /// }
+    /// ```
+    pub fn arity(&self, cx: &mut Cx) -> NeonResult<u32> {
+        // `length` is configurable and may be redefined with a value of any type
+        let length: Handle<JsValue> = self.prop(cx, "length").get()?;
+        let length = cx.coerce_to_number(length)?.value(cx);
+
+        if length.is_nan() {
+            return Ok(0);
+        }
+
+        Ok(length.clamp(0.0, u32::MAX as f64) as u32)
+    }
 }
 
 impl JsFunction {
@@ -26,7 +26,7 @@ use {
     crate::context::internal::ContextInternal,
     crate::event::{JoinError, SendThrow},
     crate::result::NeonResult,
-    crate::types::{JsFunction, JsValue},
+    crate::types::JsValue,
     std::future::Future,
     std::pin::Pin,
     std::sync::Mutex,
@@ -34,6 +34,9 @@ use {
     tokio::sync::oneshot,
 };
 
+#[cfg(feature = "napi-5")]
+use crate::{context::FunctionContext, types::JsFunction};
+
 #[cfg(any(feature = "napi-6", all(feature = "napi-5", feature = "futures")))]
 use std::sync::Arc;
 
@@ -143,6 +146,8 @@ impl JsPromise {
             internal: Some(NodeApiDeferred(deferred)),
             #[cfg(feature = "napi-6")]
             drop_queue: InstanceData::drop_queue(cx),
+            #[cfg(feature = "napi-6")]
+            drop_message: None,
         };
 
         (deferred, Handle::new_internal(JsPromise(promise)))
@@ -166,6 +171,85 @@ impl JsPromise {
         promise
     }
 
+    #[cfg(feature = "napi-5")]
+    /// Attaches Rust handlers for the fulfillment and, optionally, the rejection of the
+    /// `Promise`, returning the chained `Promise`. Equivalent to calling
+    /// `promise.then(onFulfilled, onRejected)` in JavaScript.
+    ///
+    /// Each handler is called with the settled value as its first argument. The chained
+    /// `Promise` resolves with the value returned by the handler, or is rejected with the
+    /// exception it throws. A panic in a handler rejects the chained `Promise` with an
+    /// `Error`.
+    ///
+    /// Rust cannot infer the signature of a closure wrapped in `Some`, so `on_rejected`
+    /// is usually a named function. Use [`JsPromise::catch`] to attach a rejection handler
+    /// on its own.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn or_zero(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    ///     Ok(cx.number(0))
+    /// }
+    ///
+    /// fn double_result(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    ///     let promise = cx.argument::<JsPromise>(0)?;
+    ///
+    ///     promise.then(
+    ///         &mut cx,
+    ///         |mut cx| {
+    ///             let n = cx.argument::<JsNumber>(0)?.value(&mut cx);
+    ///
+    ///             Ok(cx.number(n * 2.0))
+    ///         },
+    ///         Some(or_zero),
+    ///     )
+    /// }
+    /// ```
+    pub fn then<'cx, F, V, R, E>(
+        &self,
+        cx: &mut Cx<'cx>,
+        on_fulfilled: F,
+        on_rejected: Option<R>,
+    ) -> JsResult<'cx, JsPromise>
+    where
+        F: Fn(FunctionContext) -> JsResult<V> + 'static,
+        V: Value,
+        R: Fn(FunctionContext) -> JsResult<E> + 'static,
+        E: Value,
+    {
+        let on_fulfilled = JsFunction::new(cx, on_fulfilled)?;
+        let on_rejected = match on_rejected {
+            Some(f) => Some(JsFunction::new(cx, f)?),
+            None => None,
+        };
+
+        let mut then = self.method(cx, "then")?;
+
+        then.arg(on_fulfilled)?;
+
+        if let Some(on_rejected) = on_rejected {
+            then.arg(on_rejected)?;
+        }
+
+        then.call()
+    }
+
+    #[cfg(feature = "napi-5")]
+    /// Attaches a Rust handler for the rejection of the `Promise`, returning the chained
+    /// `Promise`. Equivalent to calling `promise.catch(onRejected)` in JavaScript.
+    ///
+    /// The handler is called with the rejection reason as its first argument. See
+    /// [`JsPromise::then`] for how the chained `Promise` is settled.
+    pub fn catch<'cx, R, E>(&self, cx: &mut Cx<'cx>, on_rejected: R) -> JsResult<'cx, JsPromise>
+    where
+        R: Fn(FunctionContext) -> JsResult<E> + 'static,
+        E: Value,
+    {
+        let on_rejected = JsFunction::new(cx, on_rejected)?;
+
+        self.method(cx, "catch")?.arg(on_rejected)?.call()
+    }
+
     #[cfg(all(feature = "napi-5", feature = "futures"))]
     #[cfg_attr(docsrs, doc(cfg(all(feature = "napi-5", feature = "futures"))))]
     /// Creates a [`Future`](std::future::Future) that can be awaited to receive the result of a
@@ -277,7 +361,8 @@ impl Object for JsPromise {}
 ///
 /// On Node-API versions less than 6, dropping a [`Deferred`] without settling will
 /// cause a panic. On Node-API 6+, the associated [`JsPromise`] will be automatically
-/// rejected.
+/// rejected, even if the `Deferred` is dropped on another thread, e.g. because the
+/// thread panicked. The error message may be customized with [`Deferred::reject_on_drop`].
 ///
 /// # Examples
 ///
@@ -286,6 +371,8 @@ pub struct Deferred {
     internal: Option<NodeApiDeferred>,
     #[cfg(feature = "napi-6")]
     drop_queue: Arc<ThreadsafeFunction<DropData>>,
+    #[cfg(feature = "napi-6")]
+    drop_message: Option<String>,
 }
 
 impl Deferred {
@@ -318,7 +405,8 @@ impl Deferred {
     ///
     /// Usage is identical to [`Deferred::settle_with`].
     ///
-    /// Returns a [`SendError`][crate::event::SendError] if sending the closure to the main JavaScript thread fails.
+    /// Returns a [`SendError`][crate::event::SendError] if sending the closure to the main JavaScript thread fails,
+    /// including if the channel is full.
     /// See [`Channel::try_send`][crate::event::Channel::try_send] for more details.
     pub fn try_settle_with<V, F>(
         self,
@@ -329,10 +417,12 @@ impl Deferred {
         V: Value,
         F: FnOnce(Cx) -> JsResult<V> + Send + 'static,
     {
-        channel.try_send(move |cx| {
-            self.try_catch_settle(cx, complete);
-            Ok(())
-        })
+        channel
+            .try_send(move |cx| {
+                self.try_catch_settle(cx, complete);
+                Ok(())
+            })
+            .map_err(SendError::from)
     }
 
     #[cfg(feature = "napi-4")]
@@ -361,6 +451,32 @@ impl Deferred {
         self.try_settle_with(channel, complete).unwrap()
     }
 
+    #[cfg(feature = "napi-6")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
+    /// Sets the message of the `Error` that rejects the [`JsPromise`] if this
+    /// `Deferred` is dropped without being settled.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// # fn example(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    /// let channel = cx.channel();
+    /// let (deferred, promise) = cx.promise();
+    /// let deferred = deferred.reject_on_drop("worker exited without a result");
+    ///
+    /// std::thread::spawn(move || {
+    ///     // If the work panics, the promise rejects instead of never settling
+    ///     let n = 42;
+    ///
+    ///     deferred.settle_with(&channel, move |mut cx| Ok(cx.number(n)));
+    /// });
+    /// # Ok(promise)
+    /// # }
+    /// ```
+    pub fn reject_on_drop(mut self, message: impl Into<String>) -> Self {
+        self.drop_message = Some(message.into());
+        self
+    }
+
     pub(crate) fn try_catch_settle<'a, C, V, F>(self, cx: C, f: F)
     where
         C: Context<'a>,
@@ -391,12 +507,12 @@ unsafe impl Send for NodeApiDeferred {}
 
 #[cfg(feature = "napi-6")]
 impl NodeApiDeferred {
-    pub(crate) unsafe fn leaked(self, env: raw::Env) {
-        sys::promise::reject_err_message(
-            env,
-            self.0,
-            "`neon::types::Deferred` was dropped without being settled",
-        );
+    pub(crate) unsafe fn leaked(self, env: raw::Env, message: Option<String>) {
+        let message = message
+            .as_deref()
+            .unwrap_or("`neon::types::Deferred` was dropped without being settled");
+
+        sys::promise::reject_err_message(env, self.0, message);
     }
 }
 
@@ -425,7 +541,10 @@ impl Drop for Deferred {
     fn drop(&mut self) {
         // If `None`, the `Deferred` has already been settled
         if let Some(internal) = self.internal.take() {
-            let _ = self.drop_queue.call(DropData::Deferred(internal), None);
+            let message = self.drop_message.take();
+            let _ = self
+                .drop_queue
+                .call(DropData::Deferred(internal, message), None);
         }
     }
 }
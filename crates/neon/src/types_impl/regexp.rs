@@ -0,0 +1,110 @@
+use super::{private::ValueInternal, Value};
+
+use crate::{
+    context::{internal::Env, Context, Cx},
+    handle::{internal::TransparentNoCopyWrapper, Handle},
+    object::Object,
+    result::{JsResult, NeonResult},
+    sys::{self, raw},
+    types::{JsArray, JsFunction},
+};
+
+/// The type of JavaScript
+/// [`RegExp`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp)
+/// objects.
+///
+/// Node-API does not provide regular expressions, so they are created with the global
+/// `RegExp` constructor and matched by calling the `RegExp.prototype` methods.
+///
+/// # Example
+///
+/// ```
+/// # use neon::prelude::*;
+/// use neon::types::JsRegExp;
+///
+/// fn is_hex_color(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+///     let input = cx.argument::<JsString>(0)?.value(&mut cx);
+///     let re = JsRegExp::new(&mut cx, "^#[0-9a-f]{6}$", "i")?;
+///     let result = re.test(&mut cx, input)?;
+///
+///     Ok(cx.boolean(result))
+/// }
+/// ```
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct JsRegExp(raw::Local);
+
+impl JsRegExp {
+    /// Creates a new `RegExp` from a `pattern` and `flags`. Throws a `SyntaxError` if the
+    /// pattern or flags are invalid.
+    pub fn new<'cx, P, F>(cx: &mut Cx<'cx>, pattern: P, flags: F) -> JsResult<'cx, JsRegExp>
+    where
+        P: AsRef<str>,
+        F: AsRef<str>,
+    {
+        let regexp: Handle<JsFunction> = cx.global("RegExp")?;
+
+        regexp
+            .bind(cx)
+            .arg(pattern.as_ref())?
+            .arg(flags.as_ref())?
+            .construct()
+    }
+
+    /// Checks if `input` matches the expression.
+    ///
+    /// Like in JavaScript, this advances `lastIndex` for global and sticky expressions.
+    pub fn test<S: AsRef<str>>(&self, cx: &mut Cx, input: S) -> NeonResult<bool> {
+        self.method(cx, "test")?.arg(input.as_ref())?.call()
+    }
+
+    /// Searches `input` for a match, returning the match array or `None` if there was
+    /// no match.
+    pub fn exec<'cx, S: AsRef<str>>(
+        &self,
+        cx: &mut Cx<'cx>,
+        input: S,
+    ) -> NeonResult<Option<Handle<'cx, JsArray>>> {
+        self.method(cx, "exec")?.arg(input.as_ref())?.call()
+    }
+
+    /// Returns the source text of the pattern.
+    pub fn source(&self, cx: &mut Cx) -> NeonResult<String> {
+        self.prop(cx, "source").get()
+    }
+
+    /// Returns the flags of the expression, e.g. `"gi"`.
+    pub fn flags(&self, cx: &mut Cx) -> NeonResult<String> {
+        self.prop(cx, "flags").get()
+    }
+}
+
+impl Value for JsRegExp {}
+
+unsafe impl TransparentNoCopyWrapper for JsRegExp {
+    type Inner = raw::Local;
+
+    fn into_inner(self) -> Self::Inner {
+        self.0
+    }
+}
+
+impl ValueInternal for JsRegExp {
+    fn name() -> &'static str {
+        "RegExp"
+    }
+
+    fn is_typeof<Other: Value>(env: Env, other: &Other) -> bool {
+        unsafe { sys::tag::is_instance_of_global(env.to_raw(), other.to_local(), "RegExp") }
+    }
+
+    fn to_local(&self) -> raw::Local {
+        self.0
+    }
+
+    unsafe fn from_local(_env: Env, h: raw::Local) -> Self {
+        JsRegExp(h)
+    }
+}
+
+impl Object for JsRegExp {}
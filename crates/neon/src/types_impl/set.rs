@@ -0,0 +1,113 @@
+use super::{private::ValueInternal, Value};
+
+use crate::{
+    context::{internal::Env, Context, Cx},
+    handle::{internal::TransparentNoCopyWrapper, Handle},
+    object::Object,
+    result::{JsResult, NeonResult},
+    sys::{self, raw},
+    types::{extract::TryIntoJs, JsFunction},
+};
+
+/// The type of JavaScript
+/// [`Set`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set)
+/// objects.
+///
+/// Methods call the `Set.prototype` methods of the object, so subclasses and
+/// monkey-patched prototypes are respected.
+///
+/// # Example
+///
+/// ```
+/// # use neon::prelude::*;
+/// use neon::types::JsSet;
+///
+/// fn unique_words(mut cx: FunctionContext) -> JsResult<JsSet> {
+///     let text = cx.argument::<JsString>(0)?.value(&mut cx);
+///     let words = JsSet::new(&mut cx)?;
+///
+///     for word in text.split_whitespace() {
+///         words.add(&mut cx, word)?;
+///     }
+///
+///     Ok(words)
+/// }
+/// ```
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct JsSet(raw::Local);
+
+impl JsSet {
+    /// Creates a new empty `Set` with the global `Set` constructor.
+    pub fn new<'cx>(cx: &mut Cx<'cx>) -> JsResult<'cx, JsSet> {
+        let set: Handle<JsFunction> = cx.global("Set")?;
+
+        set.bind(cx).construct()
+    }
+
+    /// Returns the number of values in the `Set`.
+    pub fn size(&self, cx: &mut Cx) -> NeonResult<usize> {
+        let size: f64 = self.prop(cx, "size").get()?;
+
+        Ok(size as usize)
+    }
+
+    /// Adds `value` to the `Set`.
+    pub fn add<'cx, V>(&self, cx: &mut Cx<'cx>, value: V) -> NeonResult<()>
+    where
+        V: TryIntoJs<'cx>,
+    {
+        self.method(cx, "add")?.arg(value)?.exec()
+    }
+
+    /// Checks if the `Set` contains `value`.
+    pub fn has<'cx, V>(&self, cx: &mut Cx<'cx>, value: V) -> NeonResult<bool>
+    where
+        V: TryIntoJs<'cx>,
+    {
+        self.method(cx, "has")?.arg(value)?.call()
+    }
+
+    /// Removes `value` from the `Set`. Returns `true` if the value was present.
+    pub fn delete<'cx, V>(&self, cx: &mut Cx<'cx>, value: V) -> NeonResult<bool>
+    where
+        V: TryIntoJs<'cx>,
+    {
+        self.method(cx, "delete")?.arg(value)?.call()
+    }
+
+    /// Removes all values from the `Set`.
+    pub fn clear(&self, cx: &mut Cx) -> NeonResult<()> {
+        self.method(cx, "clear")?.exec()
+    }
+}
+
+impl Value for JsSet {}
+
+unsafe impl TransparentNoCopyWrapper for JsSet {
+    type Inner = raw::Local;
+
+    fn into_inner(self) -> Self::Inner {
+        self.0
+    }
+}
+
+impl ValueInternal for JsSet {
+    fn name() -> &'static str {
+        "Set"
+    }
+
+    fn is_typeof<Other: Value>(env: Env, other: &Other) -> bool {
+        unsafe { sys::tag::is_instance_of_global(env.to_raw(), other.to_local(), "Set") }
+    }
+
+    fn to_local(&self) -> raw::Local {
+        self.0
+    }
+
+    unsafe fn from_local(_env: Env, h: raw::Local) -> Self {
+        JsSet(h)
+    }
+}
+
+impl Object for JsSet {}
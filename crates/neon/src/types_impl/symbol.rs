@@ -0,0 +1,130 @@
+use super::{private::ValueInternal, Value};
+
+use crate::{
+    context::{
+        internal::{ContextInternal, Env},
+        Context, Cx,
+    },
+    handle::{internal::TransparentNoCopyWrapper, Handle},
+    object::Object,
+    result::{JsResult, NeonResult},
+    sys::{self, raw},
+    types::{JsFunction, JsObject},
+};
+
+/// The type of JavaScript
+/// [`Symbol`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol)
+/// primitives.
+///
+/// Symbols can be used as property keys with [`Object::prop`](crate::object::Object::prop)
+/// and the other methods of [`Object`](crate::object::Object).
+///
+/// # Example
+///
+/// ```
+/// # use neon::prelude::*;
+/// use neon::types::JsSymbol;
+///
+/// fn tag_object(mut cx: FunctionContext) -> JsResult<JsObject> {
+///     let obj = cx.argument::<JsObject>(0)?;
+///     let tag = JsSymbol::for_key(&mut cx, "my-module.tag")?;
+///
+///     obj.prop(&mut cx, tag).set("tagged")?;
+///
+///     Ok(obj)
+/// }
+/// ```
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct JsSymbol(raw::Local);
+
+impl JsSymbol {
+    /// Creates a new unique symbol with a description.
+    pub fn new<'cx, S: AsRef<str>>(cx: &mut Cx<'cx>, description: S) -> Handle<'cx, JsSymbol> {
+        let description = cx.string(description);
+        let mut local: raw::Local = std::ptr::null_mut();
+
+        unsafe {
+            sys::primitive::symbol(&mut local, cx.env().to_raw(), description.to_local());
+            Handle::new_internal(JsSymbol(local))
+        }
+    }
+
+    /// Gets the symbol for `key` from the global symbol registry, creating it if it
+    /// does not exist. Equivalent to calling `Symbol.for(key)` in JavaScript.
+    pub fn for_key<'cx, S: AsRef<str>>(cx: &mut Cx<'cx>, key: S) -> JsResult<'cx, JsSymbol> {
+        let symbol: Handle<JsFunction> = cx.global("Symbol")?;
+
+        symbol.method(cx, "for")?.arg(key.as_ref())?.call()
+    }
+
+    /// Returns the description of the symbol, or `None` if it was created without one.
+    pub fn description(&self, cx: &mut Cx) -> NeonResult<Option<String>> {
+        // Box the primitive with `Object(symbol)` to read `Symbol.prototype.description`
+        let object: Handle<JsFunction> = cx.global("Object")?;
+        let symbol = Handle::new_internal(JsSymbol(self.0));
+        let wrapper: Handle<JsObject> = object.bind(cx).arg(symbol)?.call()?;
+
+        wrapper.prop(cx, "description").get()
+    }
+
+    /// The well-known `Symbol.iterator` symbol
+    pub fn iterator<'cx>(cx: &mut Cx<'cx>) -> JsResult<'cx, JsSymbol> {
+        well_known(cx, "iterator")
+    }
+
+    /// The well-known `Symbol.asyncIterator` symbol
+    pub fn async_iterator<'cx>(cx: &mut Cx<'cx>) -> JsResult<'cx, JsSymbol> {
+        well_known(cx, "asyncIterator")
+    }
+
+    /// The well-known `Symbol.hasInstance` symbol
+    pub fn has_instance<'cx>(cx: &mut Cx<'cx>) -> JsResult<'cx, JsSymbol> {
+        well_known(cx, "hasInstance")
+    }
+
+    /// The well-known `Symbol.toPrimitive` symbol
+    pub fn to_primitive<'cx>(cx: &mut Cx<'cx>) -> JsResult<'cx, JsSymbol> {
+        well_known(cx, "toPrimitive")
+    }
+
+    /// The well-known `Symbol.toStringTag` symbol
+    pub fn to_string_tag<'cx>(cx: &mut Cx<'cx>) -> JsResult<'cx, JsSymbol> {
+        well_known(cx, "toStringTag")
+    }
+}
+
+// Well-known symbols are not available from Node-API and must be read from the global
+fn well_known<'cx>(cx: &mut Cx<'cx>, name: &str) -> JsResult<'cx, JsSymbol> {
+    let symbol: Handle<JsFunction> = cx.global("Symbol")?;
+
+    symbol.prop(cx, name).get()
+}
+
+impl Value for JsSymbol {}
+
+unsafe impl TransparentNoCopyWrapper for JsSymbol {
+    type Inner = raw::Local;
+
+    fn into_inner(self) -> Self::Inner {
+        self.0
+    }
+}
+
+impl ValueInternal for JsSymbol {
+    fn name() -> &'static str {
+        "symbol"
+    }
+
+    fn is_typeof<Other: Value>(env: Env, other: &Other) -> bool {
+        unsafe { sys::tag::is_symbol(env.to_raw(), other.to_local()) }
+    }
+
+    fn to_local(&self) -> raw::Local {
+        self.0
+    }
+
+    unsafe fn from_local(_env: Env, h: raw::Local) -> Self {
+        JsSymbol(h)
+    }
+}
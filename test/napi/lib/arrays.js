@@ -21,4 +21,38 @@ describe("JsArray", function () {
   it("returns undefined when accessing outside JsArray bounds", function () {
     assert.strictEqual(addon.read_js_array([]), undefined);
   });
+
+  it("converts a JsArray to a Vec of numbers", function () {
+    assert.strictEqual(addon.sum_js_array([1, 2, 3.5]), 6.5);
+  });
+
+  it("reports the index of an element that fails to convert", function () {
+    assert.throws(
+      () => addon.sum_js_array([1, "two", 3]),
+      TypeError,
+      /element 1: expected number/
+    );
+  });
+
+  it("builds a JsArray from a slice of handles", function () {
+    const obj = {};
+    const result = addon.reverse_js_array([1, "two", obj]);
+
+    assert.deepEqual(result, [obj, "two", 1]);
+    assert.strictEqual(result[0], obj);
+  });
+
+  it("builds a JsArray from an iterator of handles", function () {
+    assert.deepEqual(addon.js_array_from_iter(4), [0, 1, 4, 9]);
+  });
+
+  it("pushes, pops, and splices a JsArray in place", function () {
+    const array = [1, 2, 3, 4];
+    const [len, popped, removed] = addon.mutate_js_array(array);
+
+    assert.strictEqual(len, 5);
+    assert.strictEqual(popped, "pushed");
+    assert.deepEqual(removed, [2, 3]);
+    assert.deepEqual(array, [1, "inserted", 4]);
+  });
 });
@@ -72,4 +72,26 @@ describe("boxed", function () {
 
     assert.throws(() => addon.person_greet(unit), /failed to downcast/);
   });
+
+  it("should report external memory to the garbage collector", function () {
+    const mb = 1024 * 1024;
+    const total = addon.adjust_external_memory(mb);
+
+    assert.strictEqual(addon.adjust_external_memory(-mb), total - mb);
+  });
+
+  it("should read the pointer of a tagged external", function () {
+    const counter = addon.tagged_counter(42);
+
+    assert.strictEqual(addon.tagged_counter_value(counter), 42);
+  });
+
+  it("should reject externals with a different type tag", function () {
+    const untagged = addon.untagged_counter(42);
+    const unit = addon.external_unit();
+
+    assert.throws(() => addon.tagged_counter_value(untagged), TypeError);
+    assert.throws(() => addon.tagged_counter_value(unit), /failed to downcast/);
+    assert.throws(() => addon.tagged_counter_value({}), /failed to downcast/);
+  });
 });
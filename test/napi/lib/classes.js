@@ -0,0 +1,72 @@
+const addon = require("..");
+const { assert } = require("chai");
+
+describe("classes", function () {
+  it("constructs instances that own Rust data", function () {
+    const a = new addon.Counter(10);
+    const b = new addon.Counter();
+
+    assert.instanceOf(a, addon.Counter);
+    assert.strictEqual(addon.Counter.name, "Counter");
+    assert.strictEqual(a.increment(), 11);
+    assert.strictEqual(a.increment(), 12);
+    assert.strictEqual(a.getCount(), 12);
+    assert.strictEqual(b.getCount(), 0);
+  });
+
+  it("defines non-enumerable methods on the prototype", function () {
+    const counter = new addon.Counter();
+
+    assert.deepEqual(Object.keys(counter), []);
+    assert.deepEqual(Object.keys(addon.Counter.prototype), []);
+    assert.deepEqual(
+      Object.getOwnPropertyNames(addon.Counter.prototype).sort(),
+      ["constructor", "getCount", "increment", "incrementWith"]
+    );
+    assert.isUndefined(addon.Counter.prototype._reset);
+  });
+
+  it("supports subclasses", function () {
+    class Doubler extends addon.Counter {
+      double() {
+        this.increment();
+        return this.increment();
+      }
+    }
+
+    const doubler = new Doubler(1);
+
+    assert.instanceOf(doubler, addon.Counter);
+    assert.strictEqual(doubler.double(), 3);
+  });
+
+  it("throws when called without new", function () {
+    assert.throws(() => addon.Counter(), TypeError, /without 'new'/);
+  });
+
+  it("throws when a method is called on another object", function () {
+    const { getCount } = addon.Counter.prototype;
+
+    assert.throws(() => getCount.call({}), TypeError, /instance of Counter/);
+    assert.throws(() => getCount.call(1), TypeError, /instance of Counter/);
+  });
+
+  it("throws instead of aliasing a mutable borrow", function () {
+    const counter = new addon.Counter();
+
+    assert.strictEqual(counter.incrementWith(() => "done"), "done");
+    assert.throws(
+      () => counter.incrementWith(() => counter.getCount()),
+      /already mutably borrowed/
+    );
+    assert.throws(
+      () => counter.incrementWith(() => counter.increment()),
+      /already borrowed/
+    );
+    assert.strictEqual(counter.getCount(), 3);
+  });
+
+  it("propagates constructor errors", function () {
+    assert.throws(() => new addon.Counter("a"), TypeError);
+  });
+});
@@ -6,5 +6,32 @@ describe("coercions", function () {
     assert.strictEqual(addon.to_string([1, 2, 3]), "1,2,3");
     assert.strictEqual(addon.to_string(new Map()), "[object Map]");
     assert.strictEqual(addon.to_string({ a: "b" }), "[object Object]");
+    assert.strictEqual(addon.to_string(42), "42");
+    assert.strictEqual(addon.to_string(null), "null");
+    assert.throws(() => addon.to_string(Symbol("s")), TypeError);
+  });
+
+  it("can coerce to a number", function () {
+    assert.strictEqual(addon.coerce_to_number("3.5"), 3.5);
+    assert.strictEqual(addon.coerce_to_number(true), 1);
+    assert.isNaN(addon.coerce_to_number("abc"));
+    assert.throws(() => addon.coerce_to_number(Symbol("s")), TypeError);
+  });
+
+  it("can coerce to a boolean", function () {
+    assert.strictEqual(addon.coerce_to_bool(""), false);
+    assert.strictEqual(addon.coerce_to_bool(0), false);
+    assert.strictEqual(addon.coerce_to_bool("false"), true);
+    assert.strictEqual(addon.coerce_to_bool({}), true);
+  });
+
+  it("propagates exceptions thrown during coercion", function () {
+    const value = {
+      valueOf() {
+        throw new Error("valueOf failed");
+      },
+    };
+
+    assert.throws(() => addon.coerce_to_number(value), /valueOf failed/);
   });
 });
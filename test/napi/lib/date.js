@@ -39,4 +39,19 @@ describe("JsDate", function () {
     const dateValue = addon.get_date_value();
     assert.equal(dateValue, 31415);
   });
+
+  it("should round trip a date through SystemTime", function () {
+    for (const time of [0, 31415, -31415, 1700000000123]) {
+      const date = addon.date_system_time_round_trip(new Date(time));
+      assert.strictEqual(date.getTime(), time);
+    }
+  });
+
+  it("should fail to convert an invalid date to SystemTime", function () {
+    assert.throws(
+      () => addon.date_system_time_round_trip(new Date(NaN)),
+      RangeError,
+      /Invalid Date/
+    );
+  });
 });
@@ -34,8 +34,182 @@ describe("errors", function () {
     assert.throws(() => addon.throw_error(msg), msg);
   });
 
+  it("should be able to throw an error with a code", function () {
+    try {
+      addon.throw_error_with_code("ERR_TEST", "Oh, no!");
+    } catch (err) {
+      assert.instanceOf(err, Error);
+      assert.strictEqual(err.code, "ERR_TEST");
+      assert.strictEqual(err.message, "Oh, no!");
+      return;
+    }
+
+    assert.fail("expected an error to be thrown");
+  });
+
+  it("should be able to throw a type error with a code", function () {
+    try {
+      addon.throw_type_error_with_code(
+        "ERR_INVALID_ARG_TYPE",
+        "Expected a string"
+      );
+    } catch (err) {
+      assert.instanceOf(err, TypeError);
+      assert.strictEqual(err.code, "ERR_INVALID_ARG_TYPE");
+      assert.strictEqual(err.message, "Expected a string");
+      return;
+    }
+
+    assert.fail("expected an error to be thrown");
+  });
+
+  it("should be able to create errors with a code without throwing", function () {
+    const [error, typeError, rangeError] = addon.new_errors_with_code();
+
+    assert.strictEqual(Object.getPrototypeOf(error), Error.prototype);
+    assert.instanceOf(typeError, TypeError);
+    assert.instanceOf(rangeError, RangeError);
+    const errors = [error, typeError, rangeError];
+
+    assert.deepEqual(
+      errors.map(({ code, message }) => [code, message]),
+      [
+        ["ERR_A", "a"],
+        ["ERR_B", "b"],
+        ["ERR_C", "c"],
+      ]
+    );
+  });
+
+  it("should be able to throw a range error with a code", function () {
+    try {
+      addon.throw_range_error_with_code("ERR_OUT_OF_RANGE", "Out of Bounds");
+    } catch (err) {
+      assert.instanceOf(err, RangeError);
+      assert.strictEqual(err.code, "ERR_OUT_OF_RANGE");
+      assert.strictEqual(err.message, "Out of Bounds");
+      return;
+    }
+
+    assert.fail("expected an error to be thrown");
+  });
+
   it("should be able to stringify a downcast error", function () {
     let msg = addon.downcast_error();
     assert.strictEqual(msg, "failed to downcast string to number");
   });
+
+  it("should inspect and propagate a pending exception", function () {
+    assert.strictEqual(addon.call_and_throw_if_pending(() => {}), false);
+    assert.throws(
+      () =>
+        addon.call_and_throw_if_pending(() => {
+          throw new TypeError("pending");
+        }),
+      TypeError,
+      "pending"
+    );
+  });
+
+  it("should throw an error from a Rust error with a cause", function () {
+    assert.strictEqual(addon.throw_from_rust("42"), undefined);
+
+    try {
+      addon.throw_from_rust("forty-two");
+      assert.fail("should have thrown");
+    } catch (err) {
+      assert.instanceOf(err, Error);
+      assert.strictEqual(err.message, "invalid config");
+      assert.instanceOf(err.cause, Error);
+      assert.strictEqual(err.cause.message, "invalid digit found in string");
+      assert.strictEqual(err.cause.cause, undefined);
+    }
+  });
+
+  it("should set the code and errno of an io::Error", function () {
+    const path = "does-not-exist.json";
+    let expected;
+
+    try {
+      require("fs").openSync(path);
+    } catch (err) {
+      expected = err;
+    }
+
+    try {
+      addon.throw_io_error_from_rust(path);
+      assert.fail("should have thrown");
+    } catch (err) {
+      assert.instanceOf(err, Error);
+      assert.strictEqual(err.code, "ENOENT");
+      assert.strictEqual(err.errno, expected.errno);
+    }
+  });
+
+  it("should throw an error resembling a Node SystemError", function () {
+    const path = "does-not-exist.json";
+    let expected;
+
+    try {
+      require("fs").openSync(path);
+    } catch (err) {
+      expected = err;
+    }
+
+    try {
+      addon.throw_system_error(path);
+      assert.fail("should have thrown");
+    } catch (err) {
+      assert.instanceOf(err, Error);
+      assert.strictEqual(err.code, "ENOENT");
+      assert.strictEqual(err.errno, expected.errno);
+      assert.strictEqual(err.syscall, "open");
+      assert.strictEqual(err.path, path);
+      assert.isTrue(err.message.startsWith("ENOENT: "));
+      assert.isTrue(err.message.endsWith(", open 'does-not-exist.json'"));
+      assert.notInclude(err.message, "os error");
+    }
+  });
+
+  it("should read the standard fields of an error", function () {
+    const description = addon.describe_error(() => {
+      const err = new RangeError("out of bounds");
+      err.code = "ERR_OUT_OF_RANGE";
+      throw err;
+    });
+
+    assert.deepEqual(description, {
+      name: "RangeError",
+      message: "out of bounds",
+      code: "ERR_OUT_OF_RANGE",
+      hasStack: true,
+    });
+  });
+
+  it("should read missing or non-string error fields as null", function () {
+    const description = addon.describe_error(() => {
+      const err = new Error();
+      err.code = 42;
+      err.stack = undefined;
+      throw err;
+    });
+
+    assert.deepEqual(description, {
+      name: "Error",
+      message: "",
+      code: null,
+      hasStack: false,
+    });
+  });
+
+  it("should throw an AggregateError with all errors", function () {
+    try {
+      addon.throw_aggregate_error(["first", "second"]);
+      assert.fail("should have thrown");
+    } catch (err) {
+      assert.instanceOf(err, AggregateError);
+      assert.strictEqual(err.message, "batch failed");
+      assert.deepEqual(err.errors.map((e) => e.message), ["first", "second"]);
+    }
+  });
 });
@@ -194,6 +194,43 @@ describe("JsFunction", function () {
     addon.check_string_and_number("string", 42);
   });
 
+  it("checks the number of arguments", function () {
+    assert.strictEqual(addon.require_one_or_two_args(1), 1);
+    assert.strictEqual(addon.require_one_or_two_args(1, 2), 2);
+    assert.throws(
+      () => addon.require_one_or_two_args(),
+      TypeError,
+      "expected at least 1 argument, got 0"
+    );
+    assert.throws(
+      () => addon.require_one_or_two_args(1, 2, 3),
+      TypeError,
+      "expected at most 2 arguments, got 3"
+    );
+  });
+
+  it("uses defaults for missing or undefined arguments", function () {
+    assert.strictEqual(addon.add_with_defaults(), 2);
+    assert.strictEqual(addon.add_with_defaults(undefined, 3), 4);
+    assert.strictEqual(addon.add_with_defaults(5), 10);
+    assert.strictEqual(addon.add_with_defaults(5, 1), 6);
+    assert.throws(() => addon.add_with_defaults(null), TypeError);
+  });
+
+  it("reads the arity of a function", function () {
+    assert.strictEqual(addon.function_arity(() => {}), 0);
+    assert.strictEqual(addon.function_arity((err, data) => {}), 2);
+    assert.strictEqual(addon.function_arity((a, b = 1, ...rest) => {}), 1);
+
+    const redefined = (length) =>
+      Object.defineProperty(() => {}, "length", { value: length });
+
+    assert.strictEqual(addon.function_arity(redefined("3")), 3);
+    assert.strictEqual(addon.function_arity(redefined(-1)), 0);
+    assert.strictEqual(addon.function_arity(redefined(NaN)), 0);
+    assert.strictEqual(addon.function_arity(redefined(2 ** 40)), 2 ** 32 - 1);
+  });
+
   it("converts a Rust panic to a throw in a function", function () {
     assert.throws(
       function () {
@@ -222,6 +259,12 @@ describe("JsFunction", function () {
     assert.equal(addon.num_arguments("a", "b", "c", "d"), 4);
   });
 
+  it("iterates over all arguments", function () {
+    assert.equal(addon.sum_arguments(), 0);
+    assert.equal(addon.sum_arguments(1, 2, 3, 4, 5, 6, 7, 8, 9, 10), 55);
+    assert.throws(() => addon.sum_arguments(1, "2"), TypeError);
+  });
+
   it("gets the right `this`-value", function () {
     var o = { iamobject: "i am object" };
     assert.equal(addon.return_this.call(o), o);
@@ -349,6 +392,21 @@ describe("JsFunction", function () {
     assert.strictEqual(addon.count_called() + 1, addon.count_called());
   });
 
+  it("should bind this and leading arguments", function () {
+    const self = {};
+    const bound = addon.bind_args(
+      function (a, b, c) {
+        return [this, a, b, c];
+      },
+      self,
+      1
+    );
+
+    assert.strictEqual(bound.length, 2);
+    assert.deepEqual(bound(2, 3), [self, 1, 2, 3]);
+    assert.strictEqual(bound(2, 3)[0], self);
+  });
+
   (global.gc ? it : it.skip)(
     "should drop function when going out of scope",
     function (cb) {
@@ -114,4 +114,34 @@ describe("Futures", () => {
       }
     });
   });
+
+  describe("Async Iterator", () => {
+    it("should iterate values received from a Rust channel", async () => {
+      const values = [];
+
+      for await (const value of addon.async_countdown(3)) {
+        values.push(value);
+      }
+
+      assert.deepStrictEqual(values, [2, 1, 0]);
+    });
+
+    it("should resolve overlapping calls to next in order", async () => {
+      const iterator = addon.async_countdown(2);
+      const results = await Promise.all([
+        iterator.next(),
+        iterator.next(),
+        iterator.next(),
+      ]);
+
+      assert.deepStrictEqual(
+        results.map(({ done, value }) => [done, value]),
+        [
+          [false, 1],
+          [false, 0],
+          [true, undefined],
+        ]
+      );
+    });
+  });
 });
@@ -20,6 +20,10 @@ describe("hello", function () {
     assert.strictEqual(addon.two, 2.1);
   });
 
+  it("should export the Node-API version", function () {
+    assert.strictEqual(addon.napiVersion, Number(process.versions.napi));
+  });
+
   it("should be able to create JS objects in rust", function () {
     assert.deepEqual(addon.rustCreated, {
       0: 1,
@@ -31,4 +35,36 @@ describe("hello", function () {
   it("should export a Rust function", function () {
     assert.strictEqual(addon.add1(2), 3.0);
   });
+
+  it("should export a lazily computed value", function () {
+    assert.strictEqual(addon.lazy_init_count(), 0);
+    assert.strictEqual(addon.lazyGreeting, "Hello, Lazy!");
+    assert.strictEqual(addon.lazyGreeting, "Hello, Lazy!");
+    assert.strictEqual(addon.lazy_init_count(), 1);
+
+    var desc = Object.getOwnPropertyDescriptor(addon, "lazyGreeting");
+    assert.strictEqual(desc.value, "Hello, Lazy!");
+    assert.isTrue(desc.enumerable);
+  });
+
+  it("should define a lazy export on exports when read through another object", function () {
+    const derived = Object.create(addon);
+
+    assert.strictEqual(derived.lazyDerived, "Hello, Derived!");
+    assert.isFalse(Object.hasOwn(derived, "lazyDerived"));
+    assert.strictEqual(
+      Object.getOwnPropertyDescriptor(addon, "lazyDerived").value,
+      "Hello, Derived!"
+    );
+    assert.strictEqual(
+      Reflect.get(addon, "lazyReceiver", 1),
+      "Hello, Receiver!"
+    );
+    assert.strictEqual(addon.lazyReceiver, "Hello, Receiver!");
+  });
+
+  it("should throw when a lazy export fails", function () {
+    assert.throws(() => addon.lazyError, /lazy failure/);
+    assert.throws(() => addon.lazyError, /failed to initialize/);
+  });
 });
@@ -56,4 +56,45 @@ describe("JsNumber", function () {
       assert.equal(addon.accept_and_return_negative_js_number(-55), -55);
     });
   });
+
+  describe("checked integers", function () {
+    it("accepts integers in range", function () {
+      assert.strictEqual(
+        addon.to_checked_integer(-2147483648, "i32"),
+        -2147483648
+      );
+      assert.strictEqual(
+        addon.to_checked_integer(4294967295, "u32"),
+        4294967295
+      );
+      assert.strictEqual(
+        addon.to_checked_integer(-Number.MAX_SAFE_INTEGER, "i64"),
+        -Number.MAX_SAFE_INTEGER
+      );
+    });
+
+    it("rejects fractional and out of range numbers", function () {
+      assert.throws(
+        () => addon.to_checked_integer(3000000000.5, "i32"),
+        RangeError,
+        "expected a 32-bit integer, got 3000000000.5"
+      );
+      assert.throws(
+        () => addon.to_checked_integer(-1, "u32"),
+        RangeError,
+        "expected an unsigned 32-bit integer, got -1"
+      );
+      assert.throws(
+        () => addon.to_checked_integer(2 ** 53, "i64"),
+        RangeError,
+        "expected a safe integer, got 9007199254740992"
+      );
+      assert.throws(() => addon.to_checked_integer(NaN, "i32"), RangeError);
+      assert.throws(
+        () => addon.to_checked_integer(Infinity, "u32"),
+        RangeError,
+        "got Infinity"
+      );
+    });
+  });
 });
@@ -91,6 +91,149 @@ describe("JsObject", function () {
     assert.equal(addon.get_own_property_names(object).length, 1);
   });
 
+  it("selects property names with options", function () {
+    const parent = { inherited: 1 };
+    const obj = Object.create(parent);
+
+    obj.own = 2;
+    obj[Symbol("symbol")] = 3;
+    Object.defineProperty(obj, "hidden", { value: 4, enumerable: false });
+
+    const names = addon.property_names_with_options;
+
+    assert.deepEqual(names(obj, false, false), ["own"]);
+    assert.deepEqual(names(obj, false, true), ["own", "hidden"]);
+    assert.deepEqual(names(obj, true, false), ["own", "inherited"]);
+  });
+
+  it("returns the keys and entries of an object", function () {
+    const obj = Object.create({ inherited: true });
+
+    obj.a = 1;
+    obj[2] = "two";
+
+    assert.deepEqual(addon.object_keys(obj), Object.keys(obj));
+    assert.deepEqual(addon.object_entries(obj), Object.entries(obj));
+  });
+
+  it("defines data and accessor properties", function () {
+    const obj = addon.define_properties({ base: 2 });
+
+    assert.deepEqual(Object.getOwnPropertyDescriptor(obj, "readonly"), {
+      value: 1,
+      writable: false,
+      enumerable: true,
+      configurable: false,
+    });
+    assert.strictEqual(obj.double, 4);
+
+    obj.double = 10;
+
+    assert.strictEqual(obj.base, 5);
+    assert.deepEqual(Object.keys(obj), ["base", "readonly"]);
+    assert.throws(() => addon.define_properties(Object.freeze({})), TypeError);
+  });
+
+  it("defines properties with any property key", function () {
+    const symbol = Symbol("key");
+    const proto = Object.getPrototypeOf({});
+
+    assert.strictEqual(addon.define_keyed_property({}, symbol, 1)[symbol], 1);
+    assert.strictEqual(addon.define_keyed_property({}, "a", 1)[0], 1);
+
+    const obj = addon.define_keyed_property({}, "__proto__", 2);
+
+    assert.strictEqual(
+      Object.getOwnPropertyDescriptor(obj, "__proto__").value,
+      2
+    );
+    assert.strictEqual(Object.getPrototypeOf(obj), proto);
+  });
+
+  it("gets own properties without the prototype chain", function () {
+    const symbol = Symbol("key");
+    const obj = Object.create({ inherited: 1 });
+
+    obj.own = undefined;
+    obj[symbol] = 2;
+
+    assert.strictEqual(addon.has_own_property(obj, "own"), true);
+    assert.strictEqual(addon.has_own_property(obj, symbol), true);
+    assert.strictEqual(addon.has_own_property(obj, "inherited"), false);
+    assert.strictEqual(addon.has_own_property(obj, "toString"), false);
+    assert.strictEqual(addon.get_own_property(obj, "own"), undefined);
+    assert.strictEqual(addon.get_own_property(obj, symbol), 2);
+    assert.strictEqual(addon.get_own_property(obj, "inherited"), null);
+    assert.strictEqual(addon.get_own_property(["a"], "0"), "a");
+    assert.throws(() => addon.has_own_property(obj, 0), TypeError);
+  });
+
+  it("gets and sets the prototype of an object", function () {
+    class Base {}
+    const obj = {};
+
+    assert.strictEqual(
+      addon.swap_prototype(obj, Base.prototype),
+      Object.prototype
+    );
+    assert.instanceOf(obj, Base);
+    assert.strictEqual(addon.swap_prototype(obj, null), Base.prototype);
+    assert.strictEqual(Object.getPrototypeOf(obj), null);
+    assert.throws(
+      () => addon.swap_prototype(Object.preventExtensions({}), null),
+      TypeError
+    );
+  });
+
+  it("assigns properties from one or more source objects", function () {
+    const nested = { x: 1 };
+    const source = { a: 1, nested };
+    const result = addon.assign_objects({ a: 0, b: 2 }, source);
+
+    assert.deepEqual(result, { a: 1, b: 2, nested });
+    assert.strictEqual(result.nested, nested);
+
+    const getter = {
+      get computed() {
+        return 3;
+      },
+    };
+
+    assert.deepEqual(addon.assign_objects({}, { a: 1 }, getter, { a: 2 }), {
+      a: 2,
+      computed: 3,
+    });
+  });
+
+  it("calls a method with `this` bound to the object", function () {
+    const greeter = {
+      greeting: "Hello",
+      greet(name) {
+        return `${this.greeting}, ${name}!`;
+      },
+    };
+
+    assert.strictEqual(
+      addon.call_greet_method(greeter, "World"),
+      "Hello, World!"
+    );
+    assert.throws(
+      () => addon.call_greet_method({ greet: 42 }, "World"),
+      TypeError,
+      "greet is not a function"
+    );
+  });
+
+  it("gets properties with string, symbol, and value keys", function () {
+    const symbol = Symbol("key");
+    const obj = { name: 1, 2: "two", [symbol]: true };
+
+    assert.strictEqual(addon.get_property_by_key(obj, "name"), 1);
+    assert.strictEqual(addon.get_property_by_key(obj, 2), "two");
+    assert.strictEqual(addon.get_property_by_key(obj, symbol), true);
+    assert.strictEqual(addon.get_property_by_key(obj, "missing"), undefined);
+  });
+
   it("data borrowed on the heap can be held longer than the handle", function () {
     const msg = "Hello, World!";
     const buf = Buffer.from(msg);
@@ -167,4 +310,117 @@ describe("JsObject", function () {
       addon.call_non_method_with_prop(obj);
     }, /not a function/);
   });
+
+  it("gets, sets, and deletes entries of a Map", function () {
+    const map = new Map([
+      ["count", 1],
+      ["removed", true],
+    ]);
+
+    assert.strictEqual(addon.js_map_operations(map), map);
+    assert.deepEqual(
+      [...map],
+      [
+        ["count", 2],
+        ["has_removed", false],
+        ["size", 1],
+      ]
+    );
+  });
+
+  it("creates a new Map", function () {
+    const map = addon.new_js_map();
+
+    assert.instanceOf(map, Map);
+    assert.strictEqual(map.get(1), "one");
+  });
+
+  it("adds, checks, and deletes values of a Set", function () {
+    assert.deepEqual(addon.js_set_operations(), [true, true, false, 1]);
+  });
+
+  it("downcasts Map and Set with instanceof", function () {
+    class MyMap extends Map {}
+
+    assert.isTrue(addon.is_js_map(new Map()));
+    assert.isTrue(addon.is_js_map(new MyMap()));
+    assert.isFalse(addon.is_js_map(new Set()));
+    assert.isFalse(addon.is_js_map({}));
+    assert.isFalse(addon.is_js_map(1));
+    assert.isTrue(addon.is_js_set(new Set()));
+    assert.isFalse(addon.is_js_set(new Map()));
+    assert.throws(() => addon.js_map_operations({}), TypeError);
+  });
+
+  it("propagates exceptions thrown by instanceof", function () {
+    const hostile = new Proxy(
+      {},
+      {
+        getPrototypeOf() {
+          throw new Error("getPrototypeOf");
+        },
+      }
+    );
+
+    assert.throws(() => addon.is_js_map(hostile), /getPrototypeOf/);
+    assert.throws(() => addon.js_map_operations(hostile), /getPrototypeOf/);
+  });
+
+  it("propagates exceptions thrown by Symbol.hasInstance", function () {
+    Object.defineProperty(Map, Symbol.hasInstance, {
+      configurable: true,
+      value() {
+        throw new Error("hasInstance");
+      },
+    });
+
+    try {
+      assert.throws(() => addon.js_map_operations(new Map()), /hasInstance/);
+      // Nothing is evaluated if an exception is already pending
+      const pending = addon.is_js_map_while_throwing(new Map());
+
+      assert.instanceOf(pending, Error);
+      assert.strictEqual(pending.message, "pending");
+    } finally {
+      delete Map[Symbol.hasInstance];
+    }
+
+    assert.isTrue(addon.is_js_map(new Map()));
+  });
+
+  it("creates and matches a RegExp", function () {
+    const re = addon.new_js_regexp("(\\d+)-(\\d+)", "g");
+
+    assert.instanceOf(re, RegExp);
+    assert.strictEqual(addon.js_regexp_parts(re), "/(\\d+)-(\\d+)/g");
+    assert.isTrue(addon.js_regexp_test(/^a/, "abc"));
+    assert.isFalse(addon.js_regexp_test(/^a/, "cba"));
+    const matches = addon.js_regexp_exec(re, "x 10-20");
+
+    assert.deepEqual([...matches], ["10-20", "10", "20"]);
+    assert.isNull(addon.js_regexp_exec(/z/, "abc"));
+    assert.throws(() => addon.new_js_regexp("(", ""), SyntaxError);
+    assert.throws(() => addon.js_regexp_test("a", "a"), TypeError);
+  });
+
+  it("parses JSON", function () {
+    assert.deepEqual(addon.json_parse('{"a":[1,true,null]}'), {
+      a: [1, true, null],
+    });
+    assert.throws(() => addon.json_parse("{"), SyntaxError);
+  });
+
+  it("stringifies JSON", function () {
+    assert.strictEqual(addon.json_stringify({ a: [1] }), '{"a":[1]}');
+    assert.strictEqual(
+      addon.json_stringify({ a: 1 }, 2),
+      JSON.stringify({ a: 1 }, null, 2)
+    );
+    assert.throws(() => addon.json_stringify(undefined), TypeError);
+
+    const circular = {};
+    circular.self = circular;
+
+    assert.throws(() => addon.json_stringify(circular), /circular/);
+  });
 });
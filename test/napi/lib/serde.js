@@ -0,0 +1,114 @@
+const addon = require("..");
+const assert = require("chai").assert;
+
+function config() {
+  return {
+    name: "neon",
+    retries: 3,
+    tags: ["a", "b"],
+    timeout: null,
+    limits: { cpu: 2, memory: 512 },
+    mode: "Fast",
+    shape: { type: "Circle", radius: 1.5 },
+    point: { Cartesian: [1, 2] },
+  };
+}
+
+describe("serde", function () {
+  it("should deserialize a value", function () {
+    assert.deepEqual(addon.serde_from_value_config(config()), config());
+  });
+
+  it("should treat undefined as None", function () {
+    const input = config();
+    delete input.timeout;
+
+    assert.deepEqual(addon.serde_from_value_config(input), config());
+  });
+
+  it("should ignore non-enumerable properties", function () {
+    const input = config();
+    Object.defineProperty(input.limits, "hidden", { value: "not a number" });
+
+    assert.deepEqual(addon.serde_from_value_config(input), config());
+  });
+
+  it("should name the field when an integer is out of range", function () {
+    const input = config();
+    input.retries = 300;
+
+    assert.throws(
+      () => addon.serde_from_value_config(input),
+      TypeError,
+      /expected u8 at `retries`/
+    );
+  });
+
+  it("should name nested fields and indices", function () {
+    const input = config();
+    input.limits.cpu = -1;
+
+    assert.throws(
+      () => addon.serde_from_value_config(input),
+      TypeError,
+      /at `limits.cpu`/
+    );
+
+    const tags = config();
+    tags.tags[1] = 5;
+
+    assert.throws(
+      () => addon.serde_from_value_config(tags),
+      TypeError,
+      /at `tags\[1\]`/
+    );
+  });
+
+  it("should reject unknown enum variants", function () {
+    const input = config();
+    input.mode = "Medium";
+
+    assert.throws(
+      () => addon.serde_from_value_config(input),
+      TypeError,
+      /unknown variant `Medium`.* at `mode`/
+    );
+  });
+
+  it("should propagate exceptions from getters", function () {
+    const input = config();
+    Object.defineProperty(input, "name", {
+      enumerable: true,
+      get() {
+        throw new Error("getter failed");
+      },
+    });
+
+    assert.throws(() => addon.serde_from_value_config(input), /getter failed/);
+  });
+
+  it("should round trip a value through to_value and from_value", function () {
+    assert.strictEqual(addon.serde_round_trip_config(), true);
+  });
+
+  it("should serialize None as null by default", function () {
+    assert.strictEqual(addon.serde_to_value_none(false), null);
+    assert.strictEqual(addon.serde_to_value_none(true), undefined);
+  });
+
+  it("should reject non-string map keys", function () {
+    assert.throws(
+      () => addon.serde_to_value_integer_keys(),
+      TypeError,
+      /map keys must be strings/
+    );
+  });
+
+  it("should reject integers that are not safe", function () {
+    assert.throws(
+      () => addon.serde_to_value_unsafe_integer(),
+      TypeError,
+      /cannot be represented exactly as a JavaScript number at `\[0\]`/
+    );
+  });
+});
@@ -16,6 +16,14 @@ describe("JsString", function () {
     it("should return the UTF-16 string length", function () {
       assert.equal(addon.return_length_utf16("hello 🥹"), 8);
     });
+    it("should round trip UTF-16", function () {
+      assert.equal(addon.round_trip_utf16("hello 🥹"), "hello 🥹");
+      assert.equal(addon.round_trip_utf16("\ud83e"), "\ud83e");
+    });
+    it("should round trip Latin-1", function () {
+      assert.equal(addon.round_trip_latin1("caf\u00e9"), "caf\u00e9");
+      assert.equal(addon.round_trip_latin1("\u0141"), "\u0041");
+    });
   });
   describe("run_as_script", function () {
     it("should return the evaluated value", function () {
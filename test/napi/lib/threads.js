@@ -29,6 +29,54 @@ const assert = require("chai").assert;
     assert.strictEqual(expected, result);
   });
 
+  it("can unwrap a root only when it is not shared", function () {
+    const expected = {};
+    const result = addon.root_try_unwrap(expected);
+
+    assert.strictEqual(expected, result);
+  });
+
+  it("can downcast a root without consuming it", function () {
+    assert.strictEqual(addon.root_downcast([]), true);
+    assert.strictEqual(addon.root_downcast({}), false);
+  });
+
+  it("compares roots by identity", function () {
+    const object = {};
+
+    assert.strictEqual(addon.root_equals(object, object), true);
+    assert.strictEqual(addon.root_equals(object, {}), false);
+  });
+
+  it("can manage the count of a reference", function () {
+    assert.deepEqual(addon.reference_counts({}), [2, 1, 0]);
+  });
+
+  it("can hold a weak reference", async function () {
+    const object = {};
+    const strong = addon.weak_reference(object);
+    const weak = addon.weak_reference({});
+
+    await new Promise((resolve) => setImmediate(resolve));
+    global.gc();
+
+    assert.strictEqual(addon.reference_get(strong), object);
+    assert.strictEqual(addon.reference_get(weak), undefined);
+  });
+
+  it("does not keep weakly referenced objects alive", async function () {
+    const object = {};
+    const alive = addon.weak_new(object);
+    const collected = addon.weak_new({});
+
+    // Collect after returning to the event loop, when no handles are open
+    await new Promise((resolve) => setImmediate(resolve));
+    addon.request_gc();
+
+    assert.strictEqual(addon.weak_get(alive), object);
+    assert.strictEqual(addon.weak_get(collected), undefined);
+  });
+
   it("should be able to callback from another thread", function (cb) {
     addon.thread_callback(cb);
   });
@@ -48,6 +96,29 @@ const assert = require("chai").assert;
     });
   });
 
+  it("should be able to callback from cloned channels", function (cb) {
+    const n = 8;
+    const calls = [];
+
+    addon.cloned_channel_callback(n, function (x) {
+      calls.push(x);
+
+      // Report once, after every send has been counted
+      if (calls.length === n) {
+        const distinct = new Set(calls).size === n;
+
+        cb(distinct ? undefined : new Error(`Duplicate callbacks: ${calls}`));
+      }
+    });
+  });
+
+  it("should be able to clone and drop roots across threads", function (cb) {
+    addon.root_clone_stress(8, function (n) {
+      assert.strictEqual(n, 8);
+      cb();
+    });
+  });
+
   it("should be able to use an async greeter", function (cb) {
     const greeter = addon.greeter_new("Hello, World!", function (greeting) {
       if (greeting === "Hello, World!") {
@@ -113,6 +184,127 @@ const assert = require("chai").assert;
     }, 10);
   });
 
+  it("should fail to join a closure from the JavaScript thread", function () {
+    assert.throws(
+      () => addon.channel_join_main_thread(),
+      /Cannot join a closure from the JavaScript thread/
+    );
+  });
+
+  it("should return full from try_send on a bounded channel", function (cb) {
+    const isFull = addon.channel_try_send_full(cb);
+
+    assert.strictEqual(isFull, true);
+  });
+
+  it("should block send on a bounded channel until space is available", function (cb) {
+    const n = 10;
+    let expected = 0;
+
+    addon.channel_bounded_send(n, function (i) {
+      assert.strictEqual(i, expected++);
+
+      if (expected === n) {
+        cb();
+      }
+    });
+  });
+
+  it("should execute channel closures in priority order", function (cb) {
+    const labels = [];
+
+    addon.channel_send_with_priority(function (label) {
+      labels.push(label);
+
+      if (labels.length === 4) {
+        assert.deepEqual(labels, ["high", "normal 1", "normal 2", "low"]);
+        cb();
+      }
+    });
+  });
+
+  it("should coalesce values set on a coalescing sender", function (cb) {
+    const n = 100000;
+    const values = [];
+
+    addon.channel_coalescing(function (latest) {
+      values.push(latest);
+
+      if (latest === n - 1) {
+        // Each value is newer than the last and most were coalesced
+        assert.deepEqual(values, [...values].sort((a, b) => a - b));
+        assert.isTrue(values.length < n / 10);
+        cb();
+      }
+    }, n);
+  });
+
+  it("should report pending closures and the high-water mark", function (cb) {
+    const before = addon.channel_metrics(function (pending, highWaterMark) {
+      assert.strictEqual(pending, 1);
+      assert.strictEqual(highWaterMark, 3);
+      cb();
+    });
+
+    assert.deepEqual(before, [2, 3]);
+  });
+
+  it("should execute boxed closures in order", function (cb) {
+    const results = [];
+
+    addon.channel_send_boxed(function (x) {
+      results.push(x);
+
+      if (results.length === 2) {
+        assert.deepEqual(results, [1, "two"]);
+        cb();
+      }
+    });
+  });
+
+  it("should execute microtasks before the next macrotask", function (cb) {
+    const order = [];
+
+    setImmediate(() => {
+      assert.deepEqual(order, ["sync", "microtask", "promise"]);
+      cb();
+    });
+
+    addon.queue_microtask(() => order.push("microtask"));
+    Promise.resolve().then(() => order.push("promise"));
+    order.push("sync");
+  });
+
+  it("should report the name of a channel to async_hooks", function (cb) {
+    const types = new Set();
+    const hook = require("async_hooks")
+      .createHook({ init: (id, type) => types.add(type) })
+      .enable();
+
+    addon.channel_named("neon-test:named-channel", () => {
+      hook.disable();
+      assert.isTrue(types.has("neon-test:named-channel"));
+      cb();
+    });
+  });
+
+  it("should execute send_local closures inline on the JavaScript thread", function (cb) {
+    let inline = true;
+
+    const n = addon.channel_send_local(function (n) {
+      assert.strictEqual(n, 42);
+      assert.strictEqual(inline, false);
+      cb();
+    });
+
+    assert.strictEqual(n, 42);
+    inline = false;
+  });
+
+  it("should propagate exceptions thrown by send_local closures", function () {
+    assert.throws(() => addon.channel_send_local_throw(), /Hello, Exception!/);
+  });
+
   it("should be able to sum numbers on the libuv pool", async function () {
     const nums = new Float64Array(
       [...new Array(10000)].map(() => Math.random())
@@ -133,6 +325,30 @@ const assert = require("chai").assert;
     assert.strictEqual(expected, actual);
   });
 
+  it("should reject a cancelled task with an AbortError", async function () {
+    const [cancelled, promise] = addon.task_cancel_before_execute();
+
+    assert.strictEqual(cancelled, true);
+
+    try {
+      await promise;
+    } catch (err) {
+      assert.instanceOf(err, Error);
+      assert.strictEqual(err.name, "AbortError");
+      assert.strictEqual(err.code, "ABORT_ERR");
+      return;
+    }
+
+    assert.fail("expected the promise to be rejected");
+  });
+
+  it("should not cancel a task that has completed", async function () {
+    const [promise, task] = addon.cancellable_task();
+
+    assert.strictEqual(await promise, 42);
+    assert.strictEqual(addon.cancel_task(task), false);
+  });
+
   it("should be able to resolve a promise from a rust thread", async function () {
     const nums = new Float64Array(
       [...new Array(10000)].map(() => Math.random())
@@ -152,6 +368,44 @@ const assert = require("chai").assert;
     }
   });
 
+  it("should reject promise with a custom message if leaked", async function () {
+    const msg = "worker exited without a result";
+
+    try {
+      await addon.leak_promise_on_panic(msg);
+    } catch (err) {
+      assert.instanceOf(err, Error);
+      assert.strictEqual(err.message, msg);
+      return;
+    }
+
+    assert.fail("promise should reject");
+  });
+
+  it("should chain Rust handlers with then", async function () {
+    assert.strictEqual(await addon.promise_then(Promise.resolve(21)), 42);
+    assert.strictEqual(await addon.promise_then(Promise.reject(new Error())), -1);
+  });
+
+  it("should chain a Rust rejection handler with catch", async function () {
+    const promise = Promise.reject(new Error("oops"));
+
+    assert.strictEqual(await addon.promise_catch(promise), "caught: oops");
+    assert.strictEqual(await addon.promise_catch(Promise.resolve(1)), 1);
+  });
+
+  it("should reject the chained promise if a handler panics", async function () {
+    try {
+      await addon.promise_then_panic(Promise.resolve());
+    } catch (err) {
+      assert.instanceOf(err, Error);
+      assert.match(err.message, /Hello, Panic!/);
+      return;
+    }
+
+    assert.fail("promise should reject");
+  });
+
   it("should throw an unhandledRejection when panicking in a channel", function (cb) {
     const msg = "Hello, Panic!";
 
@@ -565,4 +565,49 @@ describe("Typed arrays", function () {
       assert.fail("region overrun should be validated when instantiating");
     } catch (expected) {}
   });
+
+  it("creates a DataView over a region of an ArrayBuffer", function () {
+    const buffer = new ArrayBuffer(16);
+    const view = addon.new_data_view(buffer, 4, 8);
+
+    assert.instanceOf(view, DataView);
+    assert.deepEqual(addon.get_data_view_info(view), {
+      buffer,
+      byteOffset: 4,
+      byteLength: 8,
+    });
+    assert.throws(() => addon.new_data_view(buffer, 12, 8), RangeError);
+  });
+
+  it("reads and writes a DataView in either byte order", function () {
+    const view = new DataView(new ArrayBuffer(16), 1, 14);
+
+    addon.write_data_view(view);
+
+    assert.strictEqual(view.getUint16(0, false), 0x0102);
+    assert.strictEqual(view.getFloat64(2, true), 1.5);
+    assert.strictEqual(view.getInt32(10, false), -2);
+
+    view.setUint32(4, 0xdeadbeef, true);
+
+    assert.strictEqual(addon.read_data_view_u32(view, 4, true), 0xdeadbeef);
+    assert.strictEqual(addon.read_data_view_u32(view, 4, false), 0xefbeadde);
+    assert.throws(() => addon.read_data_view_u32(view, 11, true), RangeError);
+  });
+
+  it("detaches an ArrayBuffer", function () {
+    const buf = new ArrayBuffer(8);
+    const view = new Uint8Array(buf);
+
+    assert.deepEqual(addon.detach_array_buffer(buf), [false, true]);
+    assert.strictEqual(buf.byteLength, 0);
+    assert.strictEqual(view.length, 0);
+  });
+
+  it("throws when detaching a non-detachable ArrayBuffer", function () {
+    const memory = new WebAssembly.Memory({ initial: 1 });
+
+    assert.throws(() => addon.detach_array_buffer(memory.buffer), TypeError);
+    assert.strictEqual(memory.buffer.byteLength, 65536);
+  });
 });
@@ -90,4 +90,101 @@ describe("type checks", function () {
     assert(!addon.strict_equals(o1, o2));
     assert(!addon.strict_equals(o1, 17));
   });
+
+  it("same_value", function () {
+    const o = {};
+    assert(addon.same_value(17, 17));
+    assert(addon.same_value("a", "a"));
+    assert(addon.same_value(o, o));
+    assert(addon.same_value(NaN, NaN));
+    assert(!addon.same_value(0, -0));
+    assert(!addon.same_value(17, "17"));
+    assert(!addon.same_value(o, {}));
+    assert(!addon.strict_equals(NaN, NaN));
+    assert(addon.strict_equals(0, -0));
+  });
+
+  it("instance_of", function () {
+    class CustomError extends Error {}
+
+    assert(addon.instance_of(new CustomError(), CustomError));
+    assert(addon.instance_of(new CustomError(), Error));
+    assert(!addon.instance_of(new Error(), CustomError));
+    assert(!addon.instance_of(17, Number));
+
+    // Arrow functions do not have a `prototype`
+    assert.throws(() => addon.instance_of({}, () => {}), TypeError);
+  });
+
+  it("is_nullish", function () {
+    assert(addon.is_nullish(null));
+    assert(addon.is_nullish(undefined));
+    assert(!addon.is_nullish(0));
+    assert(!addon.is_nullish(""));
+    assert(!addon.is_nullish(false));
+    assert(!addon.is_nullish({}));
+  });
+
+  it("as_option", function () {
+    assert.strictEqual(addon.as_option_or_default(null, 1), 1);
+    assert.strictEqual(addon.as_option_or_default(undefined, 1), 1);
+    assert.strictEqual(addon.as_option_or_default(0, 1), 0);
+    assert.strictEqual(addon.as_option_or_default("", 1), "");
+  });
+
+  it("is_symbol", function () {
+    assert(addon.is_symbol(Symbol("a")));
+    assert(addon.is_symbol(Symbol.iterator));
+    assert(!addon.is_symbol("a"));
+    assert(!addon.is_symbol(Object(Symbol("a"))));
+  });
+
+  it("create_symbol", function () {
+    const a = addon.create_symbol("a");
+
+    assert.strictEqual(typeof a, "symbol");
+    assert.strictEqual(a.description, "a");
+    assert.notStrictEqual(a, addon.create_symbol("a"));
+  });
+
+  it("symbol_for", function () {
+    assert.strictEqual(addon.symbol_for("neon.key"), Symbol.for("neon.key"));
+  });
+
+  it("symbol_description", function () {
+    assert.strictEqual(addon.symbol_description(Symbol("a")), "a");
+    assert.strictEqual(addon.symbol_description(Symbol()), undefined);
+  });
+
+  it("symbol property keys", function () {
+    const key = Symbol("key");
+    const obj = addon.set_symbol_property({}, key, 42);
+
+    assert.strictEqual(obj[key], 42);
+    assert.strictEqual(addon.get_iterator([]), Array.prototype[Symbol.iterator]);
+  });
+
+  it("type_of", function () {
+    assert.strictEqual(addon.type_of(undefined), "undefined");
+    assert.strictEqual(addon.type_of(null), "null");
+    assert.strictEqual(addon.type_of(true), "boolean");
+    assert.strictEqual(addon.type_of(1), "number");
+    assert.strictEqual(addon.type_of(""), "string");
+    assert.strictEqual(addon.type_of(Symbol()), "symbol");
+    assert.strictEqual(addon.type_of([]), "object");
+    assert.strictEqual(addon.type_of(() => {}), "function");
+    assert.strictEqual(addon.type_of(1n), "bigint");
+  });
+
+  it("to_debug_string", function () {
+    const { inspect } = require("util");
+    const nested = { a: { b: { c: { d: 1 } } }, s: "str" };
+
+    assert.strictEqual(addon.to_debug_string(nested), inspect(nested));
+    assert.strictEqual(
+      addon.to_debug_string(nested, 0),
+      "{ a: [Object], s: 'str' }"
+    );
+    assert.strictEqual(addon.to_debug_string(1n), "1n");
+  });
 });
@@ -53,6 +53,10 @@ if (!isMainThread) {
     parentPort.postMessage("startup_complete");
   }
 
+  if (workerData === "add_cleanup_hooks") {
+    parentPort.postMessage(addon.add_cleanup_hooks());
+  }
+
   return;
 }
 
@@ -213,3 +217,25 @@ describe("Instance-local storage", () => {
     });
   });
 });
+
+describe("Cleanup hooks", () => {
+  it("should run cleanup hooks when a worker exits", (cb) => {
+    const count = addon.cleanup_hook_count();
+    const worker = new Worker(__filename, { workerData: "add_cleanup_hooks" });
+
+    worker.once("message", (removed) => {
+      assert.strictEqual(removed, true);
+      worker.terminate();
+    });
+
+    worker.once("exit", () => {
+      try {
+        // Only the hook that was not removed runs
+        assert.strictEqual(addon.cleanup_hook_count(), count + 1);
+        cb();
+      } catch (err) {
+        cb(err);
+      }
+    });
+  });
+});
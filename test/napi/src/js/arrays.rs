@@ -24,3 +24,35 @@ pub fn read_js_array(mut cx: FunctionContext) -> JsResult<JsValue> {
 
     Ok(first_element)
 }
+
+pub fn sum_js_array(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let nums = cx.argument::<JsArray>(0)?.to_vec_of::<f64>(&mut cx)?;
+
+    Ok(cx.number(nums.into_iter().sum::<f64>()))
+}
+
+pub fn reverse_js_array(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let mut values = cx.argument::<JsArray>(0)?.to_vec(&mut cx)?;
+
+    values.reverse();
+
+    JsArray::from_slice(&mut cx, &values)
+}
+
+pub fn js_array_from_iter(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let n = cx.argument::<JsNumber>(0)?.value(&mut cx) as u32;
+    let values = (0..n).map(|i| cx.number(i * i)).collect::<Vec<_>>();
+
+    JsArray::from_iter(&mut cx, values)
+}
+
+pub fn mutate_js_array(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let array = cx.argument::<JsArray>(0)?;
+    let len = array.push(&mut cx, "pushed")?;
+    let popped = array.pop(&mut cx)?;
+    let inserted = cx.string("inserted").upcast();
+    let removed = array.splice(&mut cx, 1, 2, &[inserted])?;
+    let len = cx.number(len);
+
+    JsArray::from_slice(&mut cx, &[len.upcast(), popped, removed.upcast()])
+}
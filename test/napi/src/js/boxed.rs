@@ -1,6 +1,9 @@
 use std::cell::RefCell;
 
-use neon::{prelude::*, types::extract::Boxed};
+use neon::{
+    prelude::*,
+    types::{extract::Boxed, ExternalTag, JsExternal},
+};
 
 pub struct Person {
     name: String,
@@ -88,3 +91,42 @@ fn boxed_string_concat(Boxed(this): Boxed<String>, rhs: String) -> String {
 fn boxed_string_repeat(_cx: &mut FunctionContext, this: Boxed<String>, n: f64) -> String {
     this.0.repeat(n as usize)
 }
+
+#[neon::export]
+fn adjust_external_memory(cx: &mut FunctionContext, delta: f64) -> f64 {
+    cx.adjust_external_memory(delta as i64) as f64
+}
+
+pub struct Counter(f64);
+
+impl ExternalTag for Counter {
+    const TYPE_TAG: u128 = 0x6f1c_2e8a_4b3d_47f0_9a52_d8e1_3c7b_a064;
+}
+
+fn free_counter(counter: *mut Counter) {
+    drop(unsafe { Box::from_raw(counter) });
+}
+
+pub fn tagged_counter(mut cx: FunctionContext) -> JsResult<JsExternal> {
+    let n = cx.argument::<JsNumber>(0)?.value(&mut cx);
+    let counter = Box::into_raw(Box::new(Counter(n)));
+
+    Ok(JsExternal::tagged(&mut cx, counter, free_counter))
+}
+
+pub fn untagged_counter(mut cx: FunctionContext) -> JsResult<JsExternal> {
+    let n = cx.argument::<JsNumber>(0)?.value(&mut cx);
+    let counter = Box::into_raw(Box::new(Counter(n)));
+
+    Ok(cx.external(counter, free_counter))
+}
+
+pub fn tagged_counter_value(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let external = cx.argument::<JsExternal>(0)?;
+
+    match external.tagged_pointer::<Counter, _>(&mut cx) {
+        // Safety: The tag guarantees the pointer was created by `tagged_counter`
+        Some(counter) => Ok(cx.number(unsafe { (*counter).0 })),
+        None => cx.throw_type_error("expected a Counter"),
+    }
+}
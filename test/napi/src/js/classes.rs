@@ -0,0 +1,48 @@
+use neon::prelude::*;
+
+pub struct Counter {
+    count: f64,
+}
+
+#[neon::class]
+impl Counter {
+    #[constructor]
+    fn new(mut cx: FunctionContext) -> NeonResult<Self> {
+        let count = cx.argument_opt(0);
+        let count = match count {
+            Some(count) => count
+                .downcast_or_throw::<JsNumber, _>(&mut cx)?
+                .value(&mut cx),
+            None => 0.0,
+        };
+
+        Ok(Self { count })
+    }
+
+    #[method]
+    fn increment<'cx>(&mut self, mut cx: FunctionContext<'cx>) -> JsResult<'cx, JsNumber> {
+        self.count += 1.0;
+
+        Ok(cx.number(self.count))
+    }
+
+    #[method(name = "getCount")]
+    fn get_count<'cx>(&self, mut cx: FunctionContext<'cx>) -> JsResult<'cx, JsNumber> {
+        Ok(cx.number(self.count))
+    }
+
+    // Calls back into JavaScript while holding `&mut self`
+    #[method(name = "incrementWith")]
+    fn increment_with<'cx>(&mut self, mut cx: FunctionContext<'cx>) -> JsResult<'cx, JsValue> {
+        let f = cx.argument::<JsFunction>(0)?;
+
+        self.count += 1.0;
+
+        f.bind(&mut cx).call()
+    }
+
+    // Not exposed to JavaScript
+    fn _reset(&mut self) {
+        self.count = 0.0;
+    }
+}
@@ -91,3 +91,12 @@ pub fn get_date_value(mut cx: FunctionContext) -> JsResult<JsNumber> {
     let value = date.value(&mut cx);
     Ok(cx.number(value))
 }
+
+pub fn date_system_time_round_trip(mut cx: FunctionContext) -> JsResult<JsDate> {
+    let date = cx.argument::<JsDate>(0)?;
+    let time = date
+        .to_system_time(&mut cx)
+        .or_else(|err| cx.throw_range_error(err.to_string()))?;
+
+    JsDate::from_system_time(&mut cx, time).or_throw(&mut cx)
+}
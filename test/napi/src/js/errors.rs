@@ -1,4 +1,4 @@
-use neon::prelude::*;
+use neon::{prelude::*, types::ErrnoInfo};
 
 pub fn new_error(mut cx: FunctionContext) -> JsResult<JsError> {
     let msg = cx.argument::<JsString>(0)?.value(&mut cx);
@@ -24,6 +24,37 @@ pub fn throw_error(mut cx: FunctionContext) -> JsResult<JsUndefined> {
     cx.throw_error(msg)
 }
 
+pub fn throw_type_error_with_code(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let code = cx.argument::<JsString>(0)?.value(&mut cx);
+    let msg = cx.argument::<JsString>(1)?.value(&mut cx);
+
+    cx.throw_type_error_with_code(code, msg)
+}
+
+pub fn throw_range_error_with_code(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let code = cx.argument::<JsString>(0)?.value(&mut cx);
+    let msg = cx.argument::<JsString>(1)?.value(&mut cx);
+
+    cx.throw_range_error_with_code(code, msg)
+}
+
+pub fn throw_error_with_code(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let code = cx.argument::<JsString>(0)?.value(&mut cx);
+    let msg = cx.argument::<JsString>(1)?.value(&mut cx);
+
+    cx.throw_error_with_code(code, msg)
+}
+
+pub fn new_errors_with_code(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let errors = [
+        JsError::error_with_code(&mut cx, "ERR_A", "a")?,
+        JsError::type_error_with_code(&mut cx, "ERR_B", "b")?,
+        JsError::range_error_with_code(&mut cx, "ERR_C", "c")?,
+    ];
+
+    JsArray::from_slice(&mut cx, &errors)
+}
+
 pub fn downcast_error(mut cx: FunctionContext) -> JsResult<JsString> {
     let s = cx.string("hi");
     if let Err(e) = s.downcast::<JsNumber, _>(&mut cx) {
@@ -32,3 +63,102 @@ pub fn downcast_error(mut cx: FunctionContext) -> JsResult<JsString> {
         panic!()
     }
 }
+
+pub fn call_and_throw_if_pending(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let f = cx.argument::<JsFunction>(0)?;
+
+    // Deliberately ignore the result and inspect the exception state instead
+    let _ = f.bind(&mut cx).exec();
+    let pending = cx.is_exception_pending();
+
+    cx.throw_if_pending()?;
+
+    Ok(cx.boolean(pending))
+}
+
+#[derive(Debug)]
+struct ConfigError(std::num::ParseIntError);
+
+impl std::fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "invalid config")
+    }
+}
+
+impl std::error::Error for ConfigError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.0)
+    }
+}
+
+pub fn throw_from_rust(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let input = cx.argument::<JsString>(0)?.value(&mut cx);
+
+    match input.parse::<u32>() {
+        Ok(_) => Ok(cx.undefined()),
+        Err(err) => cx.throw_from_rust(&ConfigError(err)),
+    }
+}
+
+pub fn throw_io_error_from_rust(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let path = cx.argument::<JsString>(0)?.value(&mut cx);
+
+    match std::fs::File::open(path) {
+        Ok(_) => Ok(cx.undefined()),
+        Err(err) => cx.throw_from_rust(&err),
+    }
+}
+
+pub fn throw_system_error(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let path = cx.argument::<JsString>(0)?.value(&mut cx);
+
+    match std::fs::File::open(&path) {
+        Ok(_) => Ok(cx.undefined()),
+        Err(err) => {
+            let err = cx.system_error(ErrnoInfo {
+                syscall: Some("open".into()),
+                path: Some(path),
+                ..err.into()
+            })?;
+
+            cx.throw(err)
+        }
+    }
+}
+
+pub fn describe_error(mut cx: FunctionContext) -> JsResult<JsObject> {
+    let f = cx.argument::<JsFunction>(0)?;
+    let thrown = match cx.try_catch(|cx| f.bind(cx).exec()) {
+        Ok(()) => return cx.throw_error("expected the function to throw"),
+        Err(thrown) => thrown,
+    };
+    let err = thrown.downcast_or_throw::<JsError, _>(&mut cx)?;
+
+    let name = err.name(&mut cx)?;
+    let message = err.message(&mut cx)?;
+    let code = err.code(&mut cx)?;
+    let has_stack = err.stack(&mut cx)?.is_some();
+    let description = cx.empty_object();
+
+    description.prop(&mut cx, "name").set(name)?;
+    description.prop(&mut cx, "message").set(message)?;
+    description.prop(&mut cx, "code").set(code)?;
+    description.prop(&mut cx, "hasStack").set(has_stack)?;
+
+    Ok(description)
+}
+
+pub fn throw_aggregate_error(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let messages = cx.argument::<JsArray>(0)?.to_vec(&mut cx)?;
+    let mut errors = Vec::new();
+
+    for msg in messages {
+        let msg = msg
+            .downcast_or_throw::<JsString, _>(&mut cx)?
+            .value(&mut cx);
+
+        errors.push(cx.error(msg)?);
+    }
+
+    cx.throw_aggregate_error(&errors, "batch failed")
+}
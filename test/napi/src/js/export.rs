@@ -1,3 +1,5 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+
 use neon::{
     prelude::*,
     types::extract::{Boxed, Error},
@@ -111,3 +113,24 @@ fn boxed_self(Boxed(this): Boxed<String>) -> String {
 fn boxed_string(s: String) -> Boxed<String> {
     Boxed(s)
 }
+
+static LAZY_INIT_COUNT: AtomicU32 = AtomicU32::new(0);
+
+#[neon::export]
+fn lazy_init_count() -> u32 {
+    LAZY_INIT_COUNT.load(Ordering::SeqCst)
+}
+
+pub fn export_lazy(cx: &mut ModuleContext) -> NeonResult<()> {
+    cx.export_lazy("lazyGreeting", |cx| {
+        LAZY_INIT_COUNT.fetch_add(1, Ordering::SeqCst);
+        Ok(cx.string("Hello, Lazy!"))
+    })?;
+
+    cx.export_lazy("lazyDerived", |cx| Ok(cx.string("Hello, Derived!")))?;
+    cx.export_lazy("lazyReceiver", |cx| Ok(cx.string("Hello, Receiver!")))?;
+
+    cx.export_lazy("lazyError", |cx| {
+        cx.throw_error::<_, Handle<JsValue>>("lazy failure")
+    })
+}
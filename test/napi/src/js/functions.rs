@@ -201,6 +201,22 @@ pub fn check_string_and_number(mut cx: FunctionContext) -> JsResult<JsUndefined>
     Ok(cx.undefined())
 }
 
+pub fn require_one_or_two_args(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    cx.require_args_range(1, 2)?;
+
+    Ok(cx.number(cx.len() as f64))
+}
+
+pub fn add_with_defaults(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let default = cx.number(1);
+    let a = cx.argument_or(0, default)?.value(&mut cx);
+    let b = cx
+        .argument_or_else(1, |cx| Ok(cx.number(a)))?
+        .value(&mut cx);
+
+    Ok(cx.number(a + b))
+}
+
 pub fn panic(_: FunctionContext) -> JsResult<JsUndefined> {
     panic!("zomg")
 }
@@ -216,6 +232,18 @@ pub fn num_arguments(mut cx: FunctionContext) -> JsResult<JsNumber> {
     Ok(cx.number(n as i32))
 }
 
+pub fn sum_arguments(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let mut total = 0.0;
+
+    for arg in cx.arguments() {
+        total += arg
+            .downcast_or_throw::<JsNumber, _>(&mut cx)?
+            .value(&mut cx);
+    }
+
+    Ok(cx.number(total))
+}
+
 pub fn return_this(mut cx: FunctionContext) -> JsResult<JsValue> {
     cx.this()
 }
@@ -373,3 +401,18 @@ pub fn caller_with_drop_callback(mut cx: FunctionContext) -> JsResult<JsFunction
         callback.f.to_inner(&mut cx).call(&mut cx, this, args)
     })
 }
+
+pub fn bind_args(mut cx: FunctionContext) -> JsResult<JsFunction> {
+    let f = cx.argument::<JsFunction>(0)?;
+    let this = cx.argument::<JsValue>(1)?;
+    let arg = cx.argument::<JsValue>(2)?;
+
+    f.bind_args(&mut cx, this, &[arg])
+}
+
+pub fn function_arity(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let f = cx.argument::<JsFunction>(0)?;
+    let arity = f.arity(&mut cx)?;
+
+    Ok(cx.number(arity))
+}
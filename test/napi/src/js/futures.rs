@@ -151,3 +151,18 @@ async fn await_callback(ch: Channel, cb: Root<JsFunction>) -> Result<Root<JsObje
 
     Ok(res)
 }
+
+#[neon::export]
+fn async_countdown<'cx>(cx: &mut FunctionContext<'cx>, n: f64) -> JsResult<'cx, JsObject> {
+    let (tx, rx) = tokio::sync::mpsc::channel(1);
+
+    std::thread::spawn(move || {
+        for i in (0..n as u32).rev() {
+            if tx.blocking_send(i).is_err() {
+                break;
+            }
+        }
+    });
+
+    cx.async_iterator(rx)
+}
@@ -39,3 +39,16 @@ pub fn accept_and_return_negative_js_number(mut cx: FunctionContext) -> JsResult
     let number: Handle<JsNumber> = cx.argument(0)?;
     Ok(number)
 }
+
+pub fn to_checked_integer(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let n = cx.argument::<JsNumber>(0)?;
+    let kind = cx.argument::<JsString>(1)?.value(&mut cx);
+    let n = match kind.as_str() {
+        "i32" => n.as_i32(&mut cx)? as f64,
+        "u32" => n.as_u32(&mut cx)? as f64,
+        "i64" => n.as_i64(&mut cx)? as f64,
+        _ => return cx.throw_type_error("unknown integer type"),
+    };
+
+    Ok(cx.number(n))
+}
@@ -1,6 +1,10 @@
 use std::borrow::Cow;
 
-use neon::{prelude::*, types::buffer::TypedArray};
+use neon::{
+    object::{PropertyDescriptor, PropertyNamesOptions},
+    prelude::*,
+    types::{buffer::TypedArray, JsMap, JsRegExp, JsSet},
+};
 
 pub fn return_js_global_object(mut cx: FunctionContext) -> JsResult<JsObject> {
     Ok(cx.global_object())
@@ -122,3 +126,290 @@ pub fn call_non_method_with_prop(mut cx: FunctionContext) -> JsResult<JsUndefine
     obj.prop(&mut cx, "number").bind()?.exec()?;
     Ok(cx.undefined())
 }
+
+pub fn js_map_operations(mut cx: FunctionContext) -> JsResult<JsMap> {
+    let map = cx.argument::<JsMap>(0)?;
+    let count: Option<f64> = map.get(&mut cx, "count")?;
+
+    map.set(&mut cx, "count", count.unwrap_or(0.0) + 1.0)?;
+    map.delete(&mut cx, "removed")?;
+
+    let has = map.has(&mut cx, "removed")?;
+    let size = map.size(&mut cx)? as f64;
+
+    map.set(&mut cx, "has_removed", has)?;
+    map.set(&mut cx, "size", size)?;
+
+    Ok(map)
+}
+
+pub fn new_js_map(mut cx: FunctionContext) -> JsResult<JsMap> {
+    let map = JsMap::new(&mut cx)?;
+
+    map.set(&mut cx, 1, "one")?;
+
+    Ok(map)
+}
+
+pub fn js_set_operations(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let set = JsSet::new(&mut cx)?;
+
+    set.add(&mut cx, "a")?;
+    set.add(&mut cx, "b")?;
+    set.add(&mut cx, "a")?;
+
+    let deleted = set.delete(&mut cx, "b")?;
+    let has_a = set.has(&mut cx, "a")?;
+    let has_b = set.has(&mut cx, "b")?;
+    let size = set.size(&mut cx)? as f64;
+
+    let result = cx.empty_array();
+    let values = [
+        cx.boolean(deleted).upcast::<JsValue>(),
+        cx.boolean(has_a).upcast(),
+        cx.boolean(has_b).upcast(),
+        cx.number(size).upcast(),
+    ];
+
+    for (i, value) in values.into_iter().enumerate() {
+        result.set(&mut cx, i as u32, value)?;
+    }
+
+    Ok(result)
+}
+
+pub fn is_js_map(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let val: Handle<JsValue> = cx.argument(0)?;
+    let result = val.is_a::<JsMap, _>(&mut cx);
+    Ok(cx.boolean(result))
+}
+
+// Checks for a `Map` while an exception is pending and returns the exception that is
+// still pending afterwards, or `undefined` if it was cleared
+pub fn is_js_map_while_throwing(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let val: Handle<JsValue> = cx.argument(0)?;
+    let mut is_map = false;
+    let result = cx.try_catch(|cx| {
+        let err = cx.throw_error::<_, ()>("pending");
+        is_map = val.is_a::<JsMap, _>(cx);
+        err
+    });
+
+    if is_map {
+        return cx.throw_error("unexpected Map while an exception is pending");
+    }
+
+    match result {
+        Ok(()) => Ok(cx.undefined().upcast()),
+        Err(err) => Ok(err),
+    }
+}
+
+pub fn is_js_set(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let val: Handle<JsValue> = cx.argument(0)?;
+    let result = val.is_a::<JsSet, _>(&mut cx);
+    Ok(cx.boolean(result))
+}
+
+pub fn new_js_regexp(mut cx: FunctionContext) -> JsResult<JsRegExp> {
+    let pattern = cx.argument::<JsString>(0)?.value(&mut cx);
+    let flags = cx.argument::<JsString>(1)?.value(&mut cx);
+
+    JsRegExp::new(&mut cx, pattern, flags)
+}
+
+pub fn js_regexp_test(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let re = cx.argument::<JsRegExp>(0)?;
+    let input = cx.argument::<JsString>(1)?.value(&mut cx);
+    let result = re.test(&mut cx, input)?;
+
+    Ok(cx.boolean(result))
+}
+
+pub fn js_regexp_exec(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let re = cx.argument::<JsRegExp>(0)?;
+    let input = cx.argument::<JsString>(1)?.value(&mut cx);
+
+    match re.exec(&mut cx, input)? {
+        Some(matches) => Ok(matches.upcast()),
+        None => Ok(cx.null().upcast()),
+    }
+}
+
+pub fn js_regexp_parts(mut cx: FunctionContext) -> JsResult<JsString> {
+    let re = cx.argument::<JsRegExp>(0)?;
+    let source = re.source(&mut cx)?;
+    let flags = re.flags(&mut cx)?;
+
+    Ok(cx.string(format!("/{source}/{flags}")))
+}
+
+pub fn json_parse(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let source = cx.argument::<JsString>(0)?.value(&mut cx);
+
+    cx.json_parse(source)
+}
+
+pub fn json_stringify(mut cx: FunctionContext) -> JsResult<JsString> {
+    let value = cx.argument::<JsValue>(0)?;
+    let indent = cx.argument_opt(1);
+    let indent = match indent {
+        Some(indent) => Some(
+            indent
+                .downcast_or_throw::<JsNumber, _>(&mut cx)?
+                .value(&mut cx) as u32,
+        ),
+        None => None,
+    };
+
+    cx.json_stringify(value, indent)
+}
+
+pub fn property_names_with_options(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let obj = cx.argument::<JsObject>(0)?;
+    let include_prototypes = cx.argument::<JsBoolean>(1)?.value(&mut cx);
+    let include_non_enumerable = cx.argument::<JsBoolean>(2)?.value(&mut cx);
+    let options = PropertyNamesOptions::new()
+        .include_prototypes(include_prototypes)
+        .include_non_enumerable(include_non_enumerable);
+
+    obj.property_names(&mut cx, options)
+}
+
+pub fn object_keys(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let obj = cx.argument::<JsObject>(0)?;
+    let keys = obj
+        .keys(&mut cx)?
+        .into_iter()
+        .map(|key| cx.string(key))
+        .collect::<Vec<_>>();
+
+    JsArray::from_slice(&mut cx, &keys)
+}
+
+pub fn object_entries(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let obj = cx.argument::<JsObject>(0)?;
+    let entries = obj.entries(&mut cx)?;
+    let array = cx.empty_array();
+
+    for (i, (key, value)) in entries.into_iter().enumerate() {
+        let key = cx.string(key).upcast();
+        let entry = JsArray::from_slice(&mut cx, &[key, value])?;
+
+        array.prop(&mut cx, i as u32).set(entry)?;
+    }
+
+    Ok(array)
+}
+
+pub fn get_property_by_key(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let obj = cx.argument::<JsObject>(0)?;
+    let key = cx.argument::<JsValue>(1)?;
+
+    // Owned strings are keyed by name, other values with `napi_get_property`
+    match key.downcast::<JsString, _>(&mut cx) {
+        Ok(key) => {
+            let key = key.value(&mut cx);
+
+            obj.prop(&mut cx, &key).get()
+        }
+        Err(_) => obj.prop(&mut cx, key).get(),
+    }
+}
+
+pub fn define_properties(mut cx: FunctionContext) -> JsResult<JsObject> {
+    let obj = cx.argument::<JsObject>(0)?;
+    let readonly = cx.number(1);
+    let double = JsFunction::new(&mut cx, |mut cx| {
+        let this = cx.this::<JsObject>()?;
+        let base: f64 = this.prop(&mut cx, "base").get()?;
+
+        Ok(cx.number(base * 2.0))
+    })?;
+    let set_base = JsFunction::new(&mut cx, |mut cx| {
+        let this = cx.this::<JsObject>()?;
+        let value: f64 = cx.argument::<JsNumber>(0)?.value(&mut cx);
+
+        this.prop(&mut cx, "base").set(value / 2.0)?;
+
+        Ok(cx.undefined())
+    })?;
+
+    obj.define_property(
+        &mut cx,
+        "readonly",
+        PropertyDescriptor::new().value(readonly).enumerable(true),
+    )?;
+    obj.define_property(
+        &mut cx,
+        "double",
+        PropertyDescriptor::new()
+            .getter(double)
+            .setter(set_base)
+            .configurable(true),
+    )?;
+
+    Ok(obj)
+}
+
+pub fn define_keyed_property(mut cx: FunctionContext) -> JsResult<JsObject> {
+    let obj = cx.argument::<JsObject>(0)?;
+    let key = cx.argument::<JsValue>(1)?;
+    let value = cx.argument::<JsValue>(2)?;
+
+    obj.define_property(&mut cx, key, PropertyDescriptor::new().value(value))?;
+    obj.define_property(&mut cx, 0, PropertyDescriptor::new().value(value))?;
+
+    Ok(obj)
+}
+
+pub fn get_own_property(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let obj = cx.argument::<JsObject>(0)?;
+    let key = cx.argument::<JsValue>(1)?;
+
+    match obj.get_own(&mut cx, key)? {
+        Some(value) => Ok(value),
+        None => Ok(cx.null().upcast()),
+    }
+}
+
+pub fn has_own_property(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let obj = cx.argument::<JsObject>(0)?;
+    let key = cx.argument::<JsValue>(1)?;
+    let has_own = obj.has_own(&mut cx, key)?;
+
+    Ok(cx.boolean(has_own))
+}
+
+pub fn swap_prototype(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let obj = cx.argument::<JsObject>(0)?;
+    let proto = cx.argument::<JsValue>(1)?;
+    let previous = obj.get_prototype(&mut cx)?;
+
+    obj.set_prototype(&mut cx, proto)?;
+
+    Ok(previous)
+}
+
+pub fn assign_objects(mut cx: FunctionContext) -> JsResult<JsObject> {
+    let target = cx.argument::<JsObject>(0)?;
+    let mut sources = Vec::new();
+
+    for source in cx.arguments().skip(1) {
+        sources.push(source.downcast_or_throw::<JsObject, _>(&mut cx)?);
+    }
+
+    match sources.as_slice() {
+        [source] => target.assign(&mut cx, *source)?,
+        sources => target.assign_all(&mut cx, sources)?,
+    }
+
+    Ok(target)
+}
+
+pub fn call_greet_method(mut cx: FunctionContext) -> JsResult<JsString> {
+    let obj = cx.argument::<JsObject>(0)?;
+    let name = cx.argument::<JsString>(1)?.value(&mut cx);
+
+    obj.call_method(&mut cx, "greet", (name,))
+}
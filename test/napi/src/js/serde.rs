@@ -0,0 +1,79 @@
+use std::collections::HashMap;
+
+use neon::{prelude::*, serde::SerializeOptions};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+pub struct Config {
+    name: String,
+    retries: u8,
+    tags: Vec<String>,
+    timeout: Option<f64>,
+    limits: HashMap<String, u32>,
+    mode: Mode,
+    shape: Shape,
+    point: Point,
+}
+
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+pub enum Mode {
+    Fast,
+    Slow,
+}
+
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "type")]
+pub enum Shape {
+    Circle { radius: f64 },
+    Square { side: f64 },
+}
+
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+pub enum Point {
+    Origin,
+    Cartesian(f64, f64),
+}
+
+pub fn from_value_config(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let value = cx.argument::<JsValue>(0)?;
+    let config: Config = neon::serde::from_value(&mut cx, value).or_throw(&mut cx)?;
+
+    neon::serde::to_value(&mut cx, &config).or_throw(&mut cx)
+}
+
+pub fn round_trip_config(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let config = Config {
+        name: "neon".into(),
+        retries: 3,
+        tags: vec!["a".into(), "b".into()],
+        timeout: Some(2.5),
+        limits: HashMap::from([("cpu".into(), 2), ("memory".into(), 512)]),
+        mode: Mode::Slow,
+        shape: Shape::Square { side: 4.0 },
+        point: Point::Origin,
+    };
+
+    let value = neon::serde::to_value(&mut cx, &config).or_throw(&mut cx)?;
+    let result: Config = neon::serde::from_value(&mut cx, value).or_throw(&mut cx)?;
+
+    Ok(cx.boolean(result == config))
+}
+
+pub fn to_value_none(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let none_as_undefined = cx.argument::<JsBoolean>(0)?.value(&mut cx);
+
+    SerializeOptions::new()
+        .none_as_undefined(none_as_undefined)
+        .to_value(&mut cx, &None::<f64>)
+        .or_throw(&mut cx)
+}
+
+pub fn to_value_integer_keys(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let map = HashMap::from([(1, "one")]);
+
+    neon::serde::to_value(&mut cx, &map).or_throw(&mut cx)
+}
+
+pub fn to_value_unsafe_integer(mut cx: FunctionContext) -> JsResult<JsValue> {
+    neon::serde::to_value(&mut cx, &[u64::MAX]).or_throw(&mut cx)
+}
@@ -19,6 +19,16 @@ pub fn return_length_utf16(mut cx: FunctionContext) -> JsResult<JsNumber> {
     Ok(cx.number(value.len() as f64))
 }
 
+pub fn round_trip_utf16(mut cx: FunctionContext) -> JsResult<JsString> {
+    let value = cx.argument::<JsString>(0)?.to_utf16(&mut cx);
+    Ok(JsString::from_utf16(&mut cx, &value))
+}
+
+pub fn round_trip_latin1(mut cx: FunctionContext) -> JsResult<JsString> {
+    let value = cx.argument::<JsString>(0)?.to_latin1(&mut cx);
+    Ok(JsString::from_latin1(&mut cx, &value))
+}
+
 pub fn run_string_as_script(mut cx: FunctionContext) -> JsResult<JsValue> {
     let string_script = cx.argument::<JsString>(0)?;
     eval(&mut cx, string_script)
@@ -1,6 +1,12 @@
-use std::{cell::RefCell, sync::Arc, time::Duration};
+use std::{
+    cell::RefCell,
+    sync::{Arc, Condvar, Mutex},
+    time::Duration,
+};
 
 use neon::{
+    event::{Priority, TaskHandle, TrySendError},
+    handle::{Reference, Weak},
     prelude::*,
     types::{buffer::TypedArray, extract::Error},
 };
@@ -13,6 +19,101 @@ pub fn useless_root(mut cx: FunctionContext) -> JsResult<JsObject> {
     Ok(object)
 }
 
+pub fn root_try_unwrap(mut cx: FunctionContext) -> JsResult<JsObject> {
+    let object = cx.argument::<JsObject>(0)?;
+    let root = object.root(&mut cx);
+    let clone = root.share();
+
+    // Fails while another clone exists
+    let root = match root.try_unwrap(&mut cx) {
+        Ok(_) => return cx.throw_error("expected `try_unwrap` to fail"),
+        Err(root) => root,
+    };
+
+    drop(clone);
+
+    root.try_unwrap(&mut cx)
+        .or_else(|_| cx.throw_error("expected `try_unwrap` to succeed"))
+}
+
+pub fn root_downcast(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let object = cx.argument::<JsObject>(0)?;
+    let root = object.root(&mut cx);
+
+    // Downcasting borrows the root, so it can be checked more than once
+    let is_array = root.downcast::<JsArray, _>(&mut cx).is_ok();
+    let is_object = root.downcast::<JsObject, _>(&mut cx).is_ok();
+
+    root.drop(&mut cx);
+
+    Ok(cx.boolean(is_array && is_object))
+}
+
+pub fn root_equals(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let a = cx.argument::<JsObject>(0)?.root(&mut cx);
+    let b = cx.argument::<JsObject>(1)?.root(&mut cx);
+    let equals = a.equals(&mut cx, &b);
+
+    a.drop(&mut cx);
+    b.drop(&mut cx);
+
+    Ok(cx.boolean(equals))
+}
+
+pub fn weak_reference(mut cx: FunctionContext) -> JsResult<JsBox<Reference<JsObject>>> {
+    let object = cx.argument::<JsObject>(0)?;
+    let weak = Reference::new(&mut cx, object, 0);
+
+    Ok(cx.boxed(weak))
+}
+
+pub fn reference_get(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let reference = cx.argument::<JsBox<Reference<JsObject>>>(0)?;
+
+    match (**reference).get(&mut cx) {
+        Some(object) => Ok(object.upcast()),
+        None => Ok(cx.undefined().upcast()),
+    }
+}
+
+pub fn weak_new(mut cx: FunctionContext) -> JsResult<JsBox<Weak<JsObject>>> {
+    let object = cx.argument::<JsObject>(0)?;
+    let weak = Weak::new(&mut cx, object);
+
+    Ok(cx.boxed(weak))
+}
+
+pub fn weak_get(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let weak = cx.argument::<JsBox<Weak<JsObject>>>(0)?;
+
+    match (**weak).get(&mut cx) {
+        Some(object) => Ok(object.upcast()),
+        None => Ok(cx.undefined().upcast()),
+    }
+}
+
+pub fn request_gc(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    cx.request_gc()?;
+
+    Ok(cx.undefined())
+}
+
+pub fn reference_counts(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let object = cx.argument::<JsObject>(0)?;
+    let reference = Reference::new(&mut cx, object, 1);
+    let counts = [
+        reference.ref_(&mut cx),
+        reference.unref(&mut cx),
+        reference.unref(&mut cx),
+    ];
+
+    reference.delete(&mut cx);
+
+    let counts = counts.map(|n| cx.number(n));
+
+    JsArray::from_slice(&mut cx, &counts)
+}
+
 pub fn thread_callback(mut cx: FunctionContext) -> JsResult<JsUndefined> {
     let callback = cx.argument::<JsFunction>(0)?.root(&mut cx);
     let channel = cx.channel();
@@ -49,6 +150,75 @@ pub fn multi_threaded_callback(mut cx: FunctionContext) -> JsResult<JsUndefined>
     Ok(cx.undefined())
 }
 
+pub fn cloned_channel_callback(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let n = cx.argument::<JsNumber>(0)?.value(&mut cx);
+    let callback = cx.argument::<JsFunction>(1)?.root(&mut cx);
+    let channel = cx.channel();
+
+    for i in 0..(n as usize) {
+        let callback = callback.clone(&mut cx);
+        let channel = channel.clone();
+
+        std::thread::spawn(move || {
+            channel.send(move |mut cx| {
+                callback
+                    .into_inner(&mut cx)
+                    .call_with(&cx)
+                    .arg(cx.number(i as f64))
+                    .exec(&mut cx)
+            })
+        });
+    }
+
+    callback.drop(&mut cx);
+
+    Ok(cx.undefined())
+}
+
+pub fn root_clone_stress(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let n = cx.argument::<JsNumber>(0)?.value(&mut cx) as usize;
+    let callback = cx.argument::<JsFunction>(1)?.root(&mut cx);
+    let channel = cx.channel();
+
+    // Clone and drop from many threads; none of these touch the JavaScript thread
+    let threads = (0..n)
+        .map(|_| {
+            let callback = callback.share();
+
+            std::thread::spawn(move || {
+                for _ in 0..1000 {
+                    drop(callback.share());
+                }
+
+                callback
+            })
+        })
+        .collect::<Vec<_>>();
+
+    std::thread::spawn(move || {
+        let roots = threads
+            .into_iter()
+            .map(|thread| thread.join().unwrap())
+            .collect::<Vec<_>>();
+
+        channel.send(move |mut cx| {
+            let n = roots.len();
+
+            for root in roots {
+                root.drop(&mut cx);
+            }
+
+            callback
+                .into_inner(&mut cx)
+                .call_with(&cx)
+                .arg(cx.number(n as f64))
+                .exec(&mut cx)
+        });
+    });
+
+    Ok(cx.undefined())
+}
+
 type BoxedGreeter = JsBox<RefCell<AsyncGreeter>>;
 
 pub struct AsyncGreeter {
@@ -214,6 +384,193 @@ pub fn channel_join(mut cx: FunctionContext) -> JsResult<JsUndefined> {
     Ok(cx.undefined())
 }
 
+pub fn channel_join_main_thread(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let channel = cx.channel();
+
+    // Joining on the JavaScript thread would block forever; expect an error instead
+    channel.send(|_| Ok(())).join().or_throw(&mut cx)?;
+
+    Ok(cx.undefined())
+}
+
+pub fn channel_try_send_full(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let callback = cx.argument::<JsFunction>(0)?.root(&mut cx);
+    let channel = Channel::with_capacity(&mut cx, 1);
+
+    // Closures cannot execute until control returns to the event loop, so the
+    // first send occupies the only slot
+    channel
+        .try_send(move |mut cx| {
+            let this = cx.undefined();
+
+            callback.into_inner(&mut cx).call(&mut cx, this, [])?;
+
+            Ok(())
+        })
+        .or_else(|err| cx.throw_error(err.to_string()))?;
+
+    let is_full = match channel.try_send(|_| Ok(())) {
+        Err(TrySendError::Full(_)) => true,
+        Err(err) => return cx.throw_error(err.to_string()),
+        Ok(_) => false,
+    };
+
+    Ok(cx.boolean(is_full))
+}
+
+pub fn channel_bounded_send(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let n = cx.argument::<JsNumber>(0)?.value(&mut cx) as usize;
+    let callback = Arc::new(cx.argument::<JsFunction>(1)?.root(&mut cx));
+    let channel = Channel::with_capacity(&mut cx, 2);
+
+    // Blocking sends from another thread wait for capacity instead of failing
+    std::thread::spawn(move || {
+        for i in 0..n {
+            let callback = Arc::clone(&callback);
+
+            channel.send(move |mut cx| {
+                let this = cx.undefined();
+                let args = [cx.number(i as f64).upcast()];
+                let callback = callback.to_inner(&mut cx);
+
+                callback.call(&mut cx, this, args)?;
+
+                Ok(())
+            });
+        }
+    });
+
+    Ok(cx.undefined())
+}
+
+pub fn channel_send_with_priority(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let callback = Arc::new(cx.argument::<JsFunction>(0)?.root(&mut cx));
+    let channel = cx.channel();
+
+    // Closures cannot execute until control returns to the event loop, so all of
+    // them are waiting and execute in priority order
+    for (priority, label) in [
+        (Priority::Low, "low"),
+        (Priority::Normal, "normal 1"),
+        (Priority::High, "high"),
+        (Priority::Normal, "normal 2"),
+    ] {
+        let callback = Arc::clone(&callback);
+
+        channel.send_with_priority(priority, move |mut cx| {
+            callback.to_inner(&mut cx).bind(&mut cx).arg(label)?.exec()
+        });
+    }
+
+    Ok(cx.undefined())
+}
+
+pub fn channel_send_boxed(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let callback = Arc::new(cx.argument::<JsFunction>(0)?.root(&mut cx));
+    let channel = cx.channel();
+
+    let first = Arc::clone(&callback);
+
+    // Distinct closure types share a single instantiation of `send_boxed`
+    channel.send_boxed(Box::new(move |mut cx| {
+        first.to_inner(&mut cx).bind(&mut cx).arg(1)?.exec()
+    }));
+
+    channel.send_boxed(Box::new(move |mut cx| {
+        callback.to_inner(&mut cx).bind(&mut cx).arg("two")?.exec()
+    }));
+
+    Ok(cx.undefined())
+}
+
+pub fn queue_microtask(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let callback = cx.argument::<JsFunction>(0)?.root(&mut cx);
+
+    cx.queue_microtask(move |mut cx| callback.into_inner(&mut cx).bind(&mut cx).exec())?;
+
+    Ok(cx.undefined())
+}
+
+pub fn channel_coalescing(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let callback = cx.argument::<JsFunction>(0)?.root(&mut cx);
+    let n = cx.argument::<JsNumber>(1)?.value(&mut cx);
+    let sender = cx.channel().coalescing(move |mut cx, latest: f64| {
+        callback.to_inner(&mut cx).bind(&mut cx).arg(latest)?.exec()
+    });
+
+    std::thread::spawn(move || {
+        for i in 0..(n as u32) {
+            sender.set(i as f64);
+        }
+    });
+
+    Ok(cx.undefined())
+}
+
+pub fn channel_metrics(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let callback = cx.argument::<JsFunction>(0)?.root(&mut cx);
+    let channel = Channel::new(&mut cx);
+
+    for _ in 0..2 {
+        channel.send(|_| Ok(()));
+    }
+
+    let pending = cx.number(channel.pending_len() as f64);
+    let metrics = channel.clone();
+
+    // The closure is counted as pending until it has finished executing
+    channel.send(move |mut cx| {
+        let pending = metrics.pending_len() as f64;
+        let high_water_mark = metrics.high_water_mark() as f64;
+
+        callback
+            .into_inner(&mut cx)
+            .bind(&mut cx)
+            .arg(pending)?
+            .arg(high_water_mark)?
+            .exec()
+    });
+
+    let high_water_mark = cx.number(channel.high_water_mark() as f64);
+
+    JsArray::from_slice(&mut cx, &[pending, high_water_mark])
+}
+
+pub fn channel_named(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let name = cx.argument::<JsString>(0)?.value(&mut cx);
+    let callback = cx.argument::<JsFunction>(1)?.root(&mut cx);
+    let channel = Channel::named(&mut cx, &name);
+
+    channel.send(move |mut cx| callback.into_inner(&mut cx).bind(&mut cx).exec());
+
+    Ok(cx.undefined())
+}
+
+pub fn channel_send_local(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let callback = cx.argument::<JsFunction>(0)?.root(&mut cx);
+    let channel = cx.channel();
+
+    // On the JavaScript thread, the closure executes before `send_local` returns
+    let n = channel.send_local(&mut cx, |_| Ok(42.0))?;
+
+    // From another thread, the closure is scheduled with `send`
+    std::thread::spawn(move || {
+        channel.send(move |mut cx| callback.into_inner(&mut cx).bind(&mut cx).arg(n)?.exec());
+    });
+
+    Ok(cx.number(n))
+}
+
+pub fn channel_send_local_throw(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let channel = cx.channel();
+
+    channel.send_local(&mut cx, |mut cx| {
+        cx.throw_error::<_, ()>("Hello, Exception!")
+    })?;
+
+    Ok(cx.undefined())
+}
+
 pub fn sum(mut cx: FunctionContext) -> JsResult<JsPromise> {
     let nums = cx.argument::<JsTypedArray<f64>>(0)?.as_slice(&cx).to_vec();
 
@@ -224,6 +581,58 @@ pub fn sum(mut cx: FunctionContext) -> JsResult<JsPromise> {
     Ok(promise)
 }
 
+pub fn task_cancel_before_execute(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let gate = Arc::new((Mutex::new(false), Condvar::new()));
+
+    // Occupy the worker pool so that the cancellable task cannot start executing
+    for _ in 0..128 {
+        let gate = Arc::clone(&gate);
+
+        cx.task(move || {
+            let (lock, cvar) = &*gate;
+            let _open = cvar
+                .wait_while(lock.lock().unwrap(), |open| !*open)
+                .unwrap();
+        })
+        .and_then(|_, _| Ok(()));
+    }
+
+    let (promise, task) = cx
+        .task(|| 42.0)
+        .cancellable_promise(|mut cx, n| Ok(cx.number(n)));
+
+    let cancelled = task.cancel(&mut cx);
+    let (lock, cvar) = &*gate;
+
+    *lock.lock().unwrap() = true;
+    cvar.notify_all();
+
+    let cancelled = cx.boolean(cancelled).upcast::<JsValue>();
+
+    JsArray::from_slice(&mut cx, &[cancelled, promise.upcast()])
+}
+
+pub struct Task(TaskHandle);
+
+impl Finalize for Task {}
+
+pub fn cancellable_task(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let (promise, task) = cx
+        .task(|| 42.0)
+        .cancellable_promise(|mut cx, n| Ok(cx.number(n)));
+
+    let task = cx.boxed(Task(task)).upcast::<JsValue>();
+
+    JsArray::from_slice(&mut cx, &[promise.upcast(), task])
+}
+
+pub fn cancel_task(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let task = cx.argument::<JsBox<Task>>(0)?;
+    let cancelled = task.0.cancel(&mut cx);
+
+    Ok(cx.boolean(cancelled))
+}
+
 pub fn sum_manual_promise(mut cx: FunctionContext) -> JsResult<JsPromise> {
     let nums = cx.argument::<JsTypedArray<f64>>(0)?.as_slice(&cx).to_vec();
 
@@ -260,6 +669,20 @@ pub fn leak_promise(mut cx: FunctionContext) -> JsResult<JsPromise> {
     Ok(promise)
 }
 
+pub fn leak_promise_on_panic(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    let msg = cx.argument::<JsString>(0)?.value(&mut cx);
+    let (deferred, promise) = cx.promise();
+    let deferred = deferred.reject_on_drop(msg);
+
+    std::thread::spawn(move || {
+        let _deferred = deferred;
+
+        panic!("Dropping the deferred while unwinding");
+    });
+
+    Ok(promise)
+}
+
 pub fn channel_panic(mut cx: FunctionContext) -> JsResult<JsUndefined> {
     let msg = cx.argument::<JsString>(0)?.value(&mut cx);
     let channel = cx.channel();
@@ -482,3 +905,41 @@ fn block_task_callback(ch: Channel, cb: Root<JsFunction>) -> Result<Root<JsObjec
 
     Ok(res)
 }
+
+pub fn promise_then(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    fn on_rejected(mut cx: FunctionContext) -> JsResult<JsNumber> {
+        Ok(cx.number(-1))
+    }
+
+    let promise = cx.argument::<JsPromise>(0)?;
+
+    promise.then(
+        &mut cx,
+        |mut cx| {
+            let n = cx.argument::<JsNumber>(0)?.value(&mut cx);
+
+            Ok(cx.number(n * 2.0))
+        },
+        Some(on_rejected),
+    )
+}
+
+pub fn promise_catch(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    let promise = cx.argument::<JsPromise>(0)?;
+
+    promise.catch(&mut cx, |mut cx| {
+        let message = cx.argument::<JsError>(0)?.message(&mut cx)?;
+
+        Ok(cx.string(format!("caught: {message}")))
+    })
+}
+
+pub fn promise_then_panic(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    let promise = cx.argument::<JsPromise>(0)?;
+
+    promise.then(
+        &mut cx,
+        |_| -> JsResult<JsValue> { panic!("Hello, Panic!") },
+        None::<fn(FunctionContext) -> JsResult<JsValue>>,
+    )
+}
@@ -1,6 +1,9 @@
 use neon::{
     prelude::*,
-    types::buffer::{Binary, BorrowError, TypedArray},
+    types::{
+        buffer::{Binary, BorrowError, TypedArray},
+        JsDataView,
+    },
 };
 
 pub fn return_array_buffer(mut cx: FunctionContext) -> JsResult<JsArrayBuffer> {
@@ -408,3 +411,56 @@ pub fn copy_buffer_with_borrow(mut cx: FunctionContext) -> JsResult<JsUndefined>
 
     Ok(cx.undefined())
 }
+
+pub fn new_data_view(mut cx: FunctionContext) -> JsResult<JsDataView> {
+    let buffer = cx.argument::<JsArrayBuffer>(0)?;
+    let offset = cx.argument::<JsNumber>(1)?.value(&mut cx) as usize;
+    let len = cx.argument::<JsNumber>(2)?.value(&mut cx) as usize;
+
+    JsDataView::new(&mut cx, buffer, offset, len)
+}
+
+pub fn get_data_view_info(mut cx: FunctionContext) -> JsResult<JsObject> {
+    let view = cx.argument::<JsDataView>(0)?;
+    let buffer = view.buffer(&mut cx);
+    let offset = view.byte_offset(&mut cx) as f64;
+    let length = view.byte_length(&mut cx) as f64;
+    let obj = cx.empty_object();
+
+    obj.prop(&mut cx, "buffer").set(buffer)?;
+    obj.prop(&mut cx, "byteOffset").set(offset)?;
+    obj.prop(&mut cx, "byteLength").set(length)?;
+
+    Ok(obj)
+}
+
+pub fn write_data_view(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let view = cx.argument::<JsDataView>(0)?;
+
+    view.set(&mut cx, 0, 0x0102u16, false)?;
+    view.set(&mut cx, 2, 1.5f64, true)?;
+    view.set(&mut cx, 10, -2i32, false)?;
+
+    Ok(cx.undefined())
+}
+
+pub fn read_data_view_u32(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let view = cx.argument::<JsDataView>(0)?;
+    let offset = cx.argument::<JsNumber>(1)?.value(&mut cx) as usize;
+    let little_endian = cx.argument::<JsBoolean>(2)?.value(&mut cx);
+    let value: u32 = view.get(&mut cx, offset, little_endian)?;
+
+    Ok(cx.number(value))
+}
+
+pub fn detach_array_buffer(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let buf = cx.argument::<JsArrayBuffer>(0)?;
+    let before = buf.is_detached(&mut cx);
+
+    buf.detach(&mut cx)?;
+
+    let after = buf.is_detached(&mut cx);
+    let (before, after) = (cx.boolean(before), cx.boolean(after));
+
+    JsArray::from_slice(&mut cx, &[before, after])
+}
@@ -1,4 +1,8 @@
-use neon::prelude::*;
+use neon::{
+    handle::InspectOptions,
+    prelude::*,
+    types::{JsSymbol, ValueType},
+};
 
 pub fn is_string(mut cx: FunctionContext) -> JsResult<JsBoolean> {
     let val: Handle<JsValue> = cx.argument(0)?;
@@ -72,3 +76,106 @@ pub fn strict_equals(mut cx: FunctionContext) -> JsResult<JsBoolean> {
     let eq = v1.strict_equals(&mut cx, v2);
     Ok(cx.boolean(eq))
 }
+
+pub fn same_value(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let v1: Handle<JsValue> = cx.argument(0)?;
+    let v2: Handle<JsValue> = cx.argument(1)?;
+    let eq = v1.same_value(&mut cx, v2);
+    Ok(cx.boolean(eq))
+}
+
+pub fn instance_of(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let val: Handle<JsValue> = cx.argument(0)?;
+    let constructor: Handle<JsFunction> = cx.argument(1)?;
+    let result = val.instance_of(&mut cx, constructor)?;
+    Ok(cx.boolean(result))
+}
+
+pub fn is_nullish(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let val: Handle<JsValue> = cx.argument(0)?;
+    let result = val.is_nullish(&mut cx);
+    Ok(cx.boolean(result))
+}
+
+pub fn as_option_or_default(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let val: Handle<JsValue> = cx.argument(0)?;
+    let default: Handle<JsValue> = cx.argument(1)?;
+    Ok(val.as_option(&mut cx).unwrap_or(default))
+}
+
+pub fn is_symbol(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let val: Handle<JsValue> = cx.argument(0)?;
+    let result = val.is_a::<JsSymbol, _>(&mut cx);
+    Ok(cx.boolean(result))
+}
+
+pub fn create_symbol(mut cx: FunctionContext) -> JsResult<JsSymbol> {
+    let description = cx.argument::<JsString>(0)?.value(&mut cx);
+    Ok(JsSymbol::new(&mut cx, description))
+}
+
+pub fn symbol_for(mut cx: FunctionContext) -> JsResult<JsSymbol> {
+    let key = cx.argument::<JsString>(0)?.value(&mut cx);
+    JsSymbol::for_key(&mut cx, key)
+}
+
+pub fn symbol_description(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let symbol = cx.argument::<JsSymbol>(0)?;
+    let description = symbol.description(&mut cx)?;
+    Ok(match description {
+        Some(description) => cx.string(description).upcast(),
+        None => cx.undefined().upcast(),
+    })
+}
+
+pub fn set_symbol_property(mut cx: FunctionContext) -> JsResult<JsObject> {
+    let obj = cx.argument::<JsObject>(0)?;
+    let symbol = cx.argument::<JsSymbol>(1)?;
+    let value = cx.argument::<JsValue>(2)?;
+    obj.prop(&mut cx, symbol).set(value)?;
+    Ok(obj)
+}
+
+pub fn get_iterator(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let obj = cx.argument::<JsObject>(0)?;
+    let iterator = JsSymbol::iterator(&mut cx)?;
+    obj.prop(&mut cx, iterator).get()
+}
+
+pub fn type_of(mut cx: FunctionContext) -> JsResult<JsString> {
+    let val: Handle<JsValue> = cx.argument(0)?;
+    let name = match val.type_of(&mut cx) {
+        ValueType::Undefined => "undefined",
+        ValueType::Null => "null",
+        ValueType::Boolean => "boolean",
+        ValueType::Number => "number",
+        ValueType::String => "string",
+        ValueType::Symbol => "symbol",
+        ValueType::Object => "object",
+        ValueType::Function => "function",
+        ValueType::External => "external",
+        ValueType::BigInt => "bigint",
+    };
+    Ok(cx.string(name))
+}
+
+pub fn to_debug_string(mut cx: FunctionContext) -> JsResult<JsString> {
+    let val: Handle<JsValue> = cx.argument(0)?;
+    let depth = cx.argument_opt(1);
+    let s = match depth {
+        Some(depth) => {
+            let depth = depth
+                .downcast_or_throw::<JsNumber, _>(&mut cx)?
+                .value(&mut cx);
+            let options = InspectOptions {
+                depth: Some(depth as u32),
+                ..Default::default()
+            };
+
+            val.to_debug_string_with(&mut cx, options)?
+        }
+        None => val.to_debug_string(&mut cx)?,
+    };
+
+    Ok(cx.string(s))
+}
@@ -1,4 +1,12 @@
-use std::{convert::TryFrom, sync::Mutex, thread, time::Duration};
+use std::{
+    convert::TryFrom,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+    time::Duration,
+};
 
 use once_cell::sync::{Lazy, OnceCell};
 
@@ -123,3 +131,27 @@ pub fn box_channels(mut cx: FunctionContext) -> JsResult<JsBox<Channels>> {
 
     Ok(cx.boxed(channels))
 }
+
+static CLEANUP_HOOK_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+pub fn add_cleanup_hooks(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    cx.add_cleanup_hook(|| {
+        CLEANUP_HOOK_COUNT.fetch_add(1, Ordering::SeqCst);
+    });
+
+    // Hooks run in reverse order, so this one runs first. The panic must not abort the
+    // process or prevent the other hooks from running.
+    cx.add_cleanup_hook(|| panic!("Hello, Panic!"));
+
+    let hook = cx.add_cleanup_hook(|| {
+        CLEANUP_HOOK_COUNT.fetch_add(100, Ordering::SeqCst);
+    });
+
+    let removed = hook.remove(&mut cx);
+
+    Ok(cx.boolean(removed))
+}
+
+pub fn cleanup_hook_count(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    Ok(cx.number(CLEANUP_HOOK_COUNT.load(Ordering::SeqCst) as f64))
+}
@@ -11,6 +11,7 @@ mod js {
     pub mod arrays;
     pub mod bigint;
     pub mod boxed;
+    pub mod classes;
     pub mod coercions;
     pub mod date;
     pub mod errors;
@@ -20,6 +21,7 @@ mod js {
     pub mod futures;
     pub mod numbers;
     pub mod objects;
+    pub mod serde;
     pub mod strings;
     pub mod threads;
     pub mod typedarrays;
@@ -64,6 +66,10 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
     cx.export_value("one", one)?;
     cx.export_value("two", two)?;
 
+    let napi_version = cx.napi_version();
+    let napi_version = cx.number(napi_version);
+    cx.export_value("napiVersion", napi_version)?;
+
     // Plain objects.
     let rust_created = cx.empty_object();
     {
@@ -119,10 +125,14 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
 
     cx.export_function("add1", add1)?;
 
+    js::export::export_lazy(&mut cx)?;
+
     cx.export_function("return_js_string", return_js_string)?;
     cx.export_function("return_js_string_utf16", return_js_string_utf16)?;
     cx.export_function("return_length_utf8", return_length_utf8)?;
     cx.export_function("return_length_utf16", return_length_utf16)?;
+    cx.export_function("round_trip_utf16", round_trip_utf16)?;
+    cx.export_function("round_trip_latin1", round_trip_latin1)?;
     cx.export_function("run_string_as_script", run_string_as_script)?;
 
     cx.export_function("return_js_number", return_js_number)?;
@@ -146,6 +156,7 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
         "accept_and_return_negative_js_number",
         accept_and_return_negative_js_number,
     )?;
+    cx.export_function("to_checked_integer", to_checked_integer)?;
 
     cx.export_function("return_js_function", return_js_function)?;
     cx.export_function("call_js_function", call_js_function)?;
@@ -219,11 +230,15 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
         construct_js_function_with_overloaded_result,
     )?;
     cx.export_function("num_arguments", num_arguments)?;
+    cx.export_function("sum_arguments", sum_arguments)?;
     cx.export_function("return_this", return_this)?;
     cx.export_function("require_object_this", require_object_this)?;
     cx.export_function("is_argument_zero_some", is_argument_zero_some)?;
     cx.export_function("require_argument_zero_string", require_argument_zero_string)?;
     cx.export_function("check_string_and_number", check_string_and_number)?;
+    cx.export_function("require_one_or_two_args", require_one_or_two_args)?;
+    cx.export_function("add_with_defaults", add_with_defaults)?;
+    cx.export_function("function_arity", function_arity)?;
     cx.export_function("execute_scoped", execute_scoped)?;
     cx.export_function("compute_scoped", compute_scoped)?;
     cx.export_function("recompute_scoped", recompute_scoped)?;
@@ -232,8 +247,14 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
     cx.export_function("return_js_array_with_number", return_js_array_with_number)?;
     cx.export_function("return_js_array_with_string", return_js_array_with_string)?;
     cx.export_function("read_js_array", read_js_array)?;
+    cx.export_function("sum_js_array", sum_js_array)?;
+    cx.export_function("reverse_js_array", reverse_js_array)?;
+    cx.export_function("js_array_from_iter", js_array_from_iter)?;
+    cx.export_function("mutate_js_array", mutate_js_array)?;
 
     cx.export_function("to_string", to_string)?;
+    cx.export_function("coerce_to_number", coerce_to_number)?;
+    cx.export_function("coerce_to_bool", coerce_to_bool)?;
 
     cx.export_function("return_js_global_object", return_js_global_object)?;
     cx.export_function("return_js_object", return_js_object)?;
@@ -245,6 +266,29 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
     )?;
     cx.export_function("freeze_js_object", freeze_js_object)?;
     cx.export_function("seal_js_object", seal_js_object)?;
+    cx.export_function("js_map_operations", js_map_operations)?;
+    cx.export_function("new_js_map", new_js_map)?;
+    cx.export_function("js_set_operations", js_set_operations)?;
+    cx.export_function("is_js_map", is_js_map)?;
+    cx.export_function("is_js_map_while_throwing", is_js_map_while_throwing)?;
+    cx.export_function("is_js_set", is_js_set)?;
+    cx.export_function("new_js_regexp", new_js_regexp)?;
+    cx.export_function("js_regexp_test", js_regexp_test)?;
+    cx.export_function("js_regexp_exec", js_regexp_exec)?;
+    cx.export_function("js_regexp_parts", js_regexp_parts)?;
+    cx.export_function("property_names_with_options", property_names_with_options)?;
+    cx.export_function("object_keys", object_keys)?;
+    cx.export_function("object_entries", object_entries)?;
+    cx.export_function("get_property_by_key", get_property_by_key)?;
+    cx.export_function("define_properties", define_properties)?;
+    cx.export_function("define_keyed_property", define_keyed_property)?;
+    cx.export_function("get_own_property", get_own_property)?;
+    cx.export_function("has_own_property", has_own_property)?;
+    cx.export_function("swap_prototype", swap_prototype)?;
+    cx.export_function("assign_objects", assign_objects)?;
+    cx.export_function("call_greet_method", call_greet_method)?;
+    cx.export_function("json_parse", json_parse)?;
+    cx.export_function("json_stringify", json_stringify)?;
 
     cx.export_function("return_array_buffer", return_array_buffer)?;
     cx.export_function(
@@ -268,6 +312,7 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
     )?;
     cx.export_function("read_u8_typed_array", read_u8_typed_array)?;
     cx.export_function("copy_typed_array", copy_typed_array)?;
+    cx.export_function("detach_array_buffer", detach_array_buffer)?;
     cx.export_function("return_uninitialized_buffer", return_uninitialized_buffer)?;
     cx.export_function("return_buffer", return_buffer)?;
     cx.export_function("return_external_buffer", return_external_buffer)?;
@@ -312,6 +357,10 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
     cx.export_function("write_buffer_with_borrow_mut", write_buffer_with_borrow_mut)?;
     cx.export_function("copy_buffer", copy_buffer)?;
     cx.export_function("copy_buffer_with_borrow", copy_buffer_with_borrow)?;
+    cx.export_function("new_data_view", new_data_view)?;
+    cx.export_function("get_data_view_info", get_data_view_info)?;
+    cx.export_function("write_data_view", write_data_view)?;
+    cx.export_function("read_data_view_u32", read_data_view_u32)?;
     cx.export_function("byte_length", byte_length)?;
     cx.export_function("call_nullary_method", call_nullary_method)?;
     cx.export_function("call_unary_method", call_unary_method)?;
@@ -329,6 +378,7 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
     cx.export_function("try_new_lossy_date", try_new_lossy_date)?;
     cx.export_function("nan_dates", nan_dates)?;
     cx.export_function("create_date_from_value", create_date_from_value)?;
+    cx.export_function("date_system_time_round_trip", date_system_time_round_trip)?;
     cx.export_function("create_and_get_invalid_date", create_and_get_invalid_date)?;
 
     cx.export_function("is_array", is_array)?;
@@ -343,12 +393,34 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
     cx.export_function("is_string", is_string)?;
     cx.export_function("is_undefined", is_undefined)?;
     cx.export_function("strict_equals", strict_equals)?;
+    cx.export_function("same_value", same_value)?;
+    cx.export_function("instance_of", instance_of)?;
+    cx.export_function("is_nullish", is_nullish)?;
+    cx.export_function("as_option_or_default", as_option_or_default)?;
+    cx.export_function("is_symbol", is_symbol)?;
+    cx.export_function("create_symbol", create_symbol)?;
+    cx.export_function("symbol_for", symbol_for)?;
+    cx.export_function("symbol_description", symbol_description)?;
+    cx.export_function("set_symbol_property", set_symbol_property)?;
+    cx.export_function("get_iterator", get_iterator)?;
+    cx.export_function("type_of", type_of)?;
+    cx.export_function("to_debug_string", to_debug_string)?;
 
     cx.export_function("new_error", new_error)?;
     cx.export_function("new_type_error", new_type_error)?;
     cx.export_function("new_range_error", new_range_error)?;
     cx.export_function("throw_error", throw_error)?;
+    cx.export_function("throw_error_with_code", throw_error_with_code)?;
+    cx.export_function("new_errors_with_code", new_errors_with_code)?;
+    cx.export_function("throw_type_error_with_code", throw_type_error_with_code)?;
+    cx.export_function("throw_range_error_with_code", throw_range_error_with_code)?;
     cx.export_function("downcast_error", downcast_error)?;
+    cx.export_function("call_and_throw_if_pending", call_and_throw_if_pending)?;
+    cx.export_function("throw_from_rust", throw_from_rust)?;
+    cx.export_function("throw_io_error_from_rust", throw_io_error_from_rust)?;
+    cx.export_function("throw_system_error", throw_system_error)?;
+    cx.export_function("describe_error", describe_error)?;
+    cx.export_function("throw_aggregate_error", throw_aggregate_error)?;
 
     cx.export_function("panic", panic)?;
     cx.export_function("panic_after_throw", panic_after_throw)?;
@@ -359,6 +431,7 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
     cx.export_function("assume_this_is_an_object", assume_this_is_an_object)?;
     cx.export_function("is_construct", is_construct)?;
     cx.export_function("caller_with_drop_callback", caller_with_drop_callback)?;
+    cx.export_function("bind_args", bind_args)?;
 
     cx.export_function("count_called", {
         let n = std::cell::RefCell::new(0);
@@ -383,20 +456,52 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
     cx.export_function("ref_person_greet", ref_person_greet)?;
     cx.export_function("ref_person_set_name", ref_person_set_name)?;
     cx.export_function("ref_person_fail", ref_person_fail)?;
+    cx.export_function("tagged_counter", tagged_counter)?;
+    cx.export_function("untagged_counter", untagged_counter)?;
+    cx.export_function("tagged_counter_value", tagged_counter_value)?;
     cx.export_function("external_unit", external_unit)?;
 
     cx.export_function("useless_root", useless_root)?;
     cx.export_function("thread_callback", thread_callback)?;
     cx.export_function("multi_threaded_callback", multi_threaded_callback)?;
+    cx.export_function("cloned_channel_callback", cloned_channel_callback)?;
+    cx.export_function("root_clone_stress", root_clone_stress)?;
+    cx.export_function("root_try_unwrap", root_try_unwrap)?;
+    cx.export_function("root_downcast", root_downcast)?;
+    cx.export_function("root_equals", root_equals)?;
+    cx.export_function("weak_reference", weak_reference)?;
+    cx.export_function("reference_get", reference_get)?;
+    cx.export_function("weak_new", weak_new)?;
+    cx.export_function("weak_get", weak_get)?;
+    cx.export_function("request_gc", request_gc)?;
+    cx.export_function("reference_counts", reference_counts)?;
     cx.export_function("greeter_new", greeter_new)?;
     cx.export_function("greeter_greet", greeter_greet)?;
     cx.export_function("leak_channel", leak_channel)?;
     cx.export_function("drop_global_queue", drop_global_queue)?;
     cx.export_function("channel_join", channel_join)?;
+    cx.export_function("channel_join_main_thread", channel_join_main_thread)?;
+    cx.export_function("channel_try_send_full", channel_try_send_full)?;
+    cx.export_function("channel_bounded_send", channel_bounded_send)?;
+    cx.export_function("channel_send_with_priority", channel_send_with_priority)?;
+    cx.export_function("channel_send_boxed", channel_send_boxed)?;
+    cx.export_function("queue_microtask", queue_microtask)?;
+    cx.export_function("channel_coalescing", channel_coalescing)?;
+    cx.export_function("channel_metrics", channel_metrics)?;
+    cx.export_function("channel_named", channel_named)?;
+    cx.export_function("channel_send_local", channel_send_local)?;
+    cx.export_function("channel_send_local_throw", channel_send_local_throw)?;
     cx.export_function("sum", sum)?;
     cx.export_function("sum_manual_promise", sum_manual_promise)?;
+    cx.export_function("task_cancel_before_execute", task_cancel_before_execute)?;
+    cx.export_function("cancellable_task", cancellable_task)?;
+    cx.export_function("cancel_task", cancel_task)?;
     cx.export_function("sum_rust_thread", sum_rust_thread)?;
     cx.export_function("leak_promise", leak_promise)?;
+    cx.export_function("leak_promise_on_panic", leak_promise_on_panic)?;
+    cx.export_function("promise_then", promise_then)?;
+    cx.export_function("promise_catch", promise_catch)?;
+    cx.export_function("promise_then_panic", promise_then_panic)?;
     cx.export_function("channel_panic", channel_panic)?;
     cx.export_function("channel_throw", channel_throw)?;
     cx.export_function("channel_panic_throw", channel_panic_throw)?;
@@ -427,6 +532,8 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
     cx.export_function("unstash_global_object", js::workers::unstash_global_object)?;
     cx.export_function("reject_after", js::workers::reject_after)?;
     cx.export_function("box_channels", js::workers::box_channels)?;
+    cx.export_function("add_cleanup_hooks", js::workers::add_cleanup_hooks)?;
+    cx.export_function("cleanup_hook_count", js::workers::cleanup_hook_count)?;
 
     // Futures
     cx.export_function("lazy_async_add", js::futures::lazy_async_add)?;
@@ -444,6 +551,22 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
         js::extract::extract_single_add_one,
     )?;
 
+    // Serde
+    cx.export_function("serde_from_value_config", js::serde::from_value_config)?;
+    cx.export_function("serde_round_trip_config", js::serde::round_trip_config)?;
+    cx.export_function("serde_to_value_none", js::serde::to_value_none)?;
+    cx.export_function(
+        "serde_to_value_integer_keys",
+        js::serde::to_value_integer_keys,
+    )?;
+    cx.export_function(
+        "serde_to_value_unsafe_integer",
+        js::serde::to_value_unsafe_integer,
+    )?;
+
+    // Classes
+    cx.export_class::<js::classes::Counter>()?;
+
     Ok(())
 }
 